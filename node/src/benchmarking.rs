@@ -120,7 +120,7 @@ pub fn create_benchmark_extrinsic(
 		)),
 		frame_system::CheckNonce::<runtime::Runtime>::from(nonce),
 		frame_system::CheckWeight::<runtime::Runtime>::new(),
-		pallet_transaction_payment::ChargeTransactionPayment::<runtime::Runtime>::from(0),
+		pallet_erc20_tx_payment::ChargeErcTransactionPayment::<runtime::Runtime>::new(0, false),
 	);
 
 	let raw_payload = runtime::SignedPayload::from_raw(
@@ -108,8 +108,15 @@ fn testnet_genesis(
 		"erc": {
 			"name": "Bitcoin",
 			"symbol": "BTC",
+			"decimals": 8,
 			"balances": [(whitelisted_caller::<AccountId>(), 2000)]
 			,
+		},
+		"stableErc": {
+			"name": "Stable Dollar",
+			"symbol": "SUSD",
+			"decimals": 6,
+			"balances": [(whitelisted_caller::<AccountId>(), 1000)],
 		}
 	})
 }
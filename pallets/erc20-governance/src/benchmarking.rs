@@ -0,0 +1,121 @@
+//! Benchmarking setup for pallet-erc20-governance
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+use frame_benchmarking::v2::*;
+use frame_support::{traits::Get, weights::Weight};
+use frame_system::RawOrigin as SystemOrigin;
+use sp_std::vec;
+
+const SEED: u32 = 0;
+
+/// Gives `who` enough Erc tokens to make a proposal and vote, returns how many it has
+fn fund<T: Config>(who: &T::AccountId) -> ErcBalanceOf<T> {
+	let amount = T::ProposalDeposit::get()
+		.saturating_add(T::Erc::minimum_balance())
+		.saturating_mul(10u32.into());
+
+	T::Erc::set_balance(who, amount)
+}
+
+/// Remark as long as `MaxCallLength` allows. Proposals are most expensive to store and decode
+/// when their call is the longest one
+fn longest_call<T: Config>() -> <T as Config>::RuntimeCall {
+	let remark = vec![0; T::MaxCallLength::get().saturating_sub(8) as usize];
+
+	frame_system::Call::<T>::remark { remark }.into()
+}
+
+/// Makes proposal `0` on behalf of a funded account
+fn make_proposal<T: Config>() -> Result<(), BenchmarkError> {
+	let proposer: T::AccountId = account("proposer", 0, SEED);
+	fund::<T>(&proposer);
+
+	Pallet::<T>::propose(SystemOrigin::Signed(proposer).into(), Box::new(longest_call::<T>()))?;
+
+	Ok(())
+}
+
+/// Moves to the block voting on proposal `0` ends at
+fn end_voting<T: Config>() {
+	let end = frame_system::Pallet::<T>::block_number().saturating_add(T::VotingPeriod::get());
+
+	frame_system::Pallet::<T>::set_block_number(end);
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn propose() {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+
+		#[extrinsic_call]
+		_(SystemOrigin::Signed(caller), Box::new(longest_call::<T>()));
+
+		assert!(Pallet::<T>::proposal(0).is_some());
+	}
+
+	#[benchmark]
+	fn vote() -> Result<(), BenchmarkError> {
+		make_proposal::<T>()?;
+
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = fund::<T>(&caller);
+
+		#[extrinsic_call]
+		_(SystemOrigin::Signed(caller.clone()), 0, true, amount);
+
+		assert_eq!(Pallet::<T>::vote_of(0, &caller).map(|vote| vote.amount), Some(amount));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn close() -> Result<(), BenchmarkError> {
+		make_proposal::<T>()?;
+
+		// Approved proposal is the worst case. Weight of its call is added on top of this one
+		Proposals::<T>::mutate(0, |proposal| {
+			if let Some(proposal) = proposal {
+				proposal.ayes = T::Erc::total_issuance();
+			}
+		});
+		end_voting::<T>();
+
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(SystemOrigin::Signed(caller), 0, Weight::MAX);
+
+		assert!(Pallet::<T>::proposal(0).is_none());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unlock() -> Result<(), BenchmarkError> {
+		make_proposal::<T>()?;
+
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = fund::<T>(&caller);
+		Pallet::<T>::vote(SystemOrigin::Signed(caller.clone()).into(), 0, true, amount)?;
+
+		end_voting::<T>();
+
+		#[extrinsic_call]
+		_(SystemOrigin::Signed(caller.clone()), 0);
+
+		assert!(Pallet::<T>::vote_of(0, &caller).is_none());
+
+		Ok(())
+	}
+
+	impl_benchmark_test_suite! {
+		Pallet,
+		tests::new_test_ext(),
+		tests::Test,
+	}
+}
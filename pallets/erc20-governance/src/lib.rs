@@ -0,0 +1,471 @@
+//! Lets Erc holders govern the chain instead of a sudo key.
+//!
+//! Any holder can [`propose()`](Pallet::propose()) a call, locking [`Config::ProposalDeposit`].
+//! Holders [`vote()`](Pallet::vote()) on it with tokens that are moved into this pallet's account
+//! and stay there until the voting period is over. After that anyone can
+//! [`close()`](Pallet::close()) the proposal, which dispatches its call if there were more ayes
+//! than nays and at least [`Config::Quorum`] of all tokens voted. The call is dispatched with
+//! [`RawOrigin::Approved`], so [`EnsureApproved`] can be used as `ForceOrigin` of other pallets
+
+// All pallets have to be like this because we're compiling for WebAssembly target
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+pub use weights::*;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
+	storage::with_storage_layer,
+	traits::{
+		fungible::{Inspect, Mutate},
+		tokens::Preservation,
+		EnsureOrigin,
+	},
+	BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AccountIdConversion, Dispatchable, Saturating, Zero},
+	DispatchResult, RuntimeDebug,
+};
+use sp_std::boxed::Box;
+
+/// Balance type of Erc tokens votes are counted in
+pub type ErcBalanceOf<T> =
+	<<T as Config>::Erc as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Index of a proposal, proposals are numbered in the order they are made
+pub type ProposalIndex = u32;
+
+/// Call waiting for Erc holders to vote on it
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct Proposal<T: Config> {
+	/// Account that made the proposal, gets `deposit` back once it's closed
+	pub proposer: T::AccountId,
+	/// Encoded [`Config::RuntimeCall`] that is dispatched if proposal is approved
+	pub call: BoundedVec<u8, T::MaxCallLength>,
+	pub deposit: ErcBalanceOf<T>,
+	/// Block voting ends at
+	pub end: BlockNumberFor<T>,
+	/// Tokens voted for the proposal
+	pub ayes: ErcBalanceOf<T>,
+	/// Tokens voted against the proposal
+	pub nays: ErcBalanceOf<T>,
+}
+
+/// Tokens an account voted with, they stay locked until block `unlock_at`
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Vote<Balance, BlockNumber> {
+	pub aye: bool,
+	pub amount: Balance,
+	pub unlock_at: BlockNumber,
+}
+
+pub type VoteOf<T> = Vote<ErcBalanceOf<T>, BlockNumberFor<T>>;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		dispatch::{extract_actual_weight, DispatchResultWithPostInfo},
+		pallet_prelude::*,
+		PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::Perbill;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin proposals are dispatched with, has to include [`Origin`] of this pallet
+		type RuntimeOrigin: From<RawOrigin>;
+
+		/// Calls that can be proposed
+		type RuntimeCall: Parameter
+			+ Dispatchable<
+				RuntimeOrigin = <Self as Config>::RuntimeOrigin,
+				PostInfo = PostDispatchInfo,
+			> + GetDispatchInfo
+			+ From<frame_system::Call<Self>>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Erc instance holders vote with
+		type Erc: Mutate<Self::AccountId>;
+
+		/// Id of the account that keeps proposal deposits and tokens voted with
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Number of blocks proposal is voted on
+		#[pallet::constant]
+		type VotingPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Tokens proposer locks until proposal is closed. Must be either zero or at least minimum
+		/// balance of `Erc`
+		#[pallet::constant]
+		type ProposalDeposit: Get<ErcBalanceOf<Self>>;
+
+		/// Share of all Erc tokens that has to vote on proposal for it to be approved
+		#[pallet::constant]
+		type Quorum: Get<Perbill>;
+
+		/// Max length of encoded call proposal can make
+		#[pallet::constant]
+		type MaxCallLength: Get<u32>;
+
+		/// Max number of proposals that aren't closed yet
+		#[pallet::constant]
+		type MaxProposals: Get<u32>;
+	}
+
+	/// Origin calls of approved proposals are dispatched with
+	#[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	pub enum RawOrigin {
+		/// Proposal was approved by Erc holders
+		Approved,
+	}
+
+	#[pallet::origin]
+	pub type Origin = RawOrigin;
+
+	/// Index the next proposal gets
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_count)]
+	pub type ProposalCount<T: Config> = StorageValue<_, ProposalIndex, ValueQuery>;
+
+	/// Proposals that are voted on or wait to be closed
+	#[pallet::storage]
+	#[pallet::getter(fn proposal)]
+	pub type Proposals<T: Config> =
+		CountedStorageMap<_, Twox64Concat, ProposalIndex, Proposal<T>, OptionQuery>;
+
+	/// Votes on proposals. Entry is kept after proposal is closed, until voter takes its tokens
+	/// back with [`Pallet::unlock()`]
+	#[pallet::storage]
+	#[pallet::getter(fn vote_of)]
+	pub type Votes<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ProposalIndex,
+		Blake2_128Concat,
+		T::AccountId,
+		VoteOf<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub (super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		Proposed { index: ProposalIndex, proposer: T::AccountId, end: BlockNumberFor<T> },
+		Voted { index: ProposalIndex, who: T::AccountId, aye: bool, amount: ErcBalanceOf<T> },
+		/// Voting on proposal is over, its call is dispatched if it was `approved`
+		Closed {
+			index: ProposalIndex,
+			ayes: ErcBalanceOf<T>,
+			nays: ErcBalanceOf<T>,
+			approved: bool,
+		},
+		/// Call of approved proposal was dispatched
+		Executed { index: ProposalIndex, result: DispatchResult },
+		Unlocked { index: ProposalIndex, who: T::AccountId, amount: ErcBalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// There are already `MaxProposals` proposals that aren't closed
+		TooManyProposals,
+		/// Encoded call is longer than `MaxCallLength`
+		CallTooLong,
+		/// There's no open proposal with such index
+		UnknownProposal,
+		/// Voting on proposal is already over
+		VotingClosed,
+		/// Voting on proposal isn't over yet
+		VotingOngoing,
+		/// Account has already voted on this proposal
+		AlreadyVoted,
+		/// Vote has to be at least minimum balance of Erc
+		VoteTooSmall,
+		/// Account didn't vote on this proposal or already unlocked its tokens
+		NotVoted,
+		/// Tokens can't be unlocked until voting on the proposal is over
+		StillLocked,
+		/// Weight of proposed call is more than `call_weight_bound`
+		WrongCallWeight,
+		/// Proposed call can't be decoded anymore, most likely runtime was upgraded since
+		UndecodableCall,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn integrity_test() {
+			let deposit = T::ProposalDeposit::get();
+
+			assert!(
+				deposit.is_zero() || deposit >= T::Erc::minimum_balance(),
+				"`ProposalDeposit` must be either zero or at least minimum balance of `Erc`"
+			);
+		}
+	}
+
+	#[pallet::call(weight(<T as Config>::WeightInfo))]
+	impl<T: Config> Pallet<T> {
+		/// Proposes `call` to Erc holders, locking [`Config::ProposalDeposit`] of the caller's
+		/// tokens until the proposal is closed
+		#[pallet::call_index(0)]
+		pub fn propose(
+			origin: OriginFor<T>,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+
+			ensure!(<Proposals<T>>::count() < T::MaxProposals::get(), Error::<T>::TooManyProposals);
+
+			let call = BoundedVec::try_from(call.encode()).map_err(|_| Error::<T>::CallTooLong)?;
+
+			let deposit = Self::lock(&proposer, T::ProposalDeposit::get())?;
+
+			let index = Self::proposal_count();
+			<ProposalCount<T>>::put(index.saturating_add(1));
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let end = now.saturating_add(T::VotingPeriod::get());
+
+			<Proposals<T>>::insert(
+				index,
+				Proposal {
+					proposer: proposer.clone(),
+					call,
+					deposit,
+					end,
+					ayes: Zero::zero(),
+					nays: Zero::zero(),
+				},
+			);
+
+			Self::deposit_event(Event::Proposed { index, proposer, end });
+
+			Ok(())
+		}
+
+		/// Votes on proposal `index` with `amount` of the caller's tokens. They are locked until
+		/// voting on the proposal is over and can be taken back with [`unlock()`](Pallet::unlock())
+		/// after that. Every account votes on a proposal once
+		#[pallet::call_index(1)]
+		pub fn vote(
+			origin: OriginFor<T>,
+			index: ProposalIndex,
+			aye: bool,
+			amount: ErcBalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// Pallet account never holds less than minimum balance this way, so giving one vote
+			// back can't take remainder of the others along with it
+			ensure!(
+				!amount.is_zero() && amount >= T::Erc::minimum_balance(),
+				Error::<T>::VoteTooSmall
+			);
+			ensure!(!<Votes<T>>::contains_key(index, &who), Error::<T>::AlreadyVoted);
+
+			// Dust left on the voter's account goes along with the vote and counts as part of it
+			let amount = Self::lock(&who, amount)?;
+
+			<Proposals<T>>::try_mutate(index, |proposal| {
+				let proposal = proposal.as_mut().ok_or(Error::<T>::UnknownProposal)?;
+
+				let now = frame_system::Pallet::<T>::block_number();
+				ensure!(now < proposal.end, Error::<T>::VotingClosed);
+
+				if aye {
+					proposal.ayes.saturating_accrue(amount);
+				} else {
+					proposal.nays.saturating_accrue(amount);
+				}
+
+				<Votes<T>>::insert(index, &who, Vote { aye, amount, unlock_at: proposal.end });
+
+				Ok::<_, DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::Voted { index, who, aye, amount });
+
+			Ok(())
+		}
+
+		/// Closes proposal `index` once voting on it is over and gives the deposit back to the
+		/// proposer. If the proposal was approved its call is dispatched with
+		/// [`RawOrigin::Approved`], `call_weight_bound` has to cover weight of that call. Deposit
+		/// that can't be given back right away, e.g. while Erc is paused or the proposer is frozen,
+		/// is added to the proposer's vote and taken back with [`unlock()`](Pallet::unlock())
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::close().saturating_add(*call_weight_bound))]
+		pub fn close(
+			origin: OriginFor<T>,
+			index: ProposalIndex,
+			call_weight_bound: Weight,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let proposal = Self::proposal(index).ok_or(Error::<T>::UnknownProposal)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now >= proposal.end, Error::<T>::VotingOngoing);
+
+			<Proposals<T>>::remove(index);
+			Self::return_deposit(index, &proposal);
+
+			let approved = Self::is_approved(&proposal);
+
+			Self::deposit_event(Event::Closed {
+				index,
+				ayes: proposal.ayes,
+				nays: proposal.nays,
+				approved,
+			});
+
+			if !approved {
+				return Ok(Some(T::WeightInfo::close()).into())
+			}
+
+			// Call was encoded by this runtime, it only fails to decode after an upgrade changed
+			// it. There's nothing to dispatch then, but the proposal is still closed
+			let Ok(call) = <T as Config>::RuntimeCall::decode(&mut &proposal.call[..]) else {
+				let result: DispatchResult = Err(Error::<T>::UndecodableCall.into());
+				Self::deposit_event(Event::Executed { index, result });
+
+				return Ok(Some(T::WeightInfo::close()).into())
+			};
+
+			let info = call.get_dispatch_info();
+			ensure!(info.weight.all_lte(call_weight_bound), Error::<T>::WrongCallWeight);
+
+			let result = call.dispatch(RawOrigin::Approved.into());
+			let call_weight = extract_actual_weight(&result, &info);
+
+			Self::deposit_event(Event::Executed {
+				index,
+				result: result.map(|_| ()).map_err(|e| e.error),
+			});
+
+			Ok(Some(T::WeightInfo::close().saturating_add(call_weight)).into())
+		}
+
+		/// Gives the caller back tokens it voted on proposal `index` with, once voting on it is
+		/// over
+		#[pallet::call_index(3)]
+		pub fn unlock(origin: OriginFor<T>, index: ProposalIndex) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let vote = <Votes<T>>::take(index, &who).ok_or(Error::<T>::NotVoted)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now >= vote.unlock_at, Error::<T>::StillLocked);
+
+			Self::release(&who, vote.amount)?;
+
+			Self::deposit_event(Event::Unlocked { index, who, amount: vote.amount });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Account that keeps proposal deposits and tokens voted with
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// Proposal is approved when it has more ayes than nays and at least [`Config::Quorum`] of
+		/// all Erc tokens voted on it
+		pub fn is_approved(proposal: &Proposal<T>) -> bool {
+			let turnout = proposal.ayes.saturating_add(proposal.nays);
+			let quorum = T::Quorum::get().mul_ceil(T::Erc::total_issuance());
+
+			proposal.ayes > proposal.nays && turnout >= quorum
+		}
+
+		/// Moves `amount` of tokens of `who` into [`Pallet::account_id()`] and returns how much was
+		/// actually moved, which includes dust that had to go along with it
+		fn lock(
+			who: &T::AccountId,
+			amount: ErcBalanceOf<T>,
+		) -> Result<ErcBalanceOf<T>, DispatchError> {
+			T::Erc::transfer(who, &Self::account_id(), amount, Preservation::Expendable)
+		}
+
+		/// Gives `amount` of tokens kept in [`Pallet::account_id()`] to `who`
+		fn release(who: &T::AccountId, amount: ErcBalanceOf<T>) -> DispatchResult {
+			T::Erc::transfer(&Self::account_id(), who, amount, Preservation::Expendable)?;
+
+			Ok(())
+		}
+
+		/// Gives deposit of proposal `index` back to its proposer. Closing the proposal must not
+		/// depend on it, otherwise a paused token could never be unpaused by a proposal. So when
+		/// it fails, the deposit is added to the proposer's vote on the proposal instead
+		fn return_deposit(index: ProposalIndex, proposal: &Proposal<T>) {
+			let released =
+				with_storage_layer(|| Self::release(&proposal.proposer, proposal.deposit)).is_ok();
+
+			if released {
+				return
+			}
+
+			<Votes<T>>::mutate(index, &proposal.proposer, |vote| {
+				let vote = vote.get_or_insert(Vote {
+					aye: false,
+					amount: Zero::zero(),
+					unlock_at: proposal.end,
+				});
+
+				vote.amount.saturating_accrue(proposal.deposit);
+			});
+		}
+	}
+}
+
+/// Passes for calls of proposals approved by Erc holders, so it can be used as `ForceOrigin` of
+/// other pallets
+pub struct EnsureApproved;
+
+impl<O: Into<Result<RawOrigin, O>> + From<RawOrigin>> EnsureOrigin<O> for EnsureApproved {
+	type Success = ();
+
+	fn try_origin(o: O) -> Result<Self::Success, O> {
+		o.into().map(|RawOrigin::Approved| ())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<O, ()> {
+		Ok(O::from(RawOrigin::Approved))
+	}
+}
@@ -0,0 +1,251 @@
+use crate::{self as pallet_erc20_governance, Config, EnsureApproved, Error, Event};
+use frame_support::{
+	assert_noop, assert_ok, derive_impl, parameter_types,
+	traits::{ConstU32, ConstU64, ConstU8},
+	weights::Weight,
+	PalletId,
+};
+use sp_runtime::{
+	testing::{TestSignature, UintAuthorityId},
+	BuildStorage, Perbill,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+pub type ErcBalance = u32;
+
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+pub const CHARLIE: u64 = 3;
+
+parameter_types! {
+	pub const GovernancePalletId: PalletId = PalletId(*b"erc/govn");
+	pub const Quorum: Perbill = Perbill::from_percent(50);
+}
+
+/// Only needed to satisfy `pallet_erc20::Config`, permits aren't benchmarked here
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TestSigner;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_erc20::BenchmarkHelper<u64, UintAuthorityId, TestSignature> for TestSigner {
+	fn signer() -> (UintAuthorityId, u64) {
+		(UintAuthorityId(1), 1)
+	}
+
+	fn sign(signer: &UintAuthorityId, message: &[u8]) -> TestSignature {
+		TestSignature(signer.0, message.to_vec())
+	}
+}
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Sudo: pallet_sudo,
+		Erc: pallet_erc20,
+		Governance: pallet_erc20_governance,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+#[derive_impl(pallet_sudo::config_preludes::TestDefaultConfig)]
+impl pallet_sudo::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+}
+
+impl pallet_erc20::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Supply = ConstU32<{ u32::MAX }>;
+	type MaxNameLength = ConstU8<50>;
+	type MaxSymbolLength = ConstU8<50>;
+	type MaxUriLength = ConstU32<128>;
+	type MaxDescriptionLength = ConstU32<256>;
+	type MaxBatchTransfers = ConstU32<128>;
+	type MaxVestingSchedules = ConstU32<28>;
+	type MinVestedTransfer = ConstU32<100>;
+	type OnTransfer = ();
+	type BeforeTransfer = ();
+	type OffchainSignature = TestSignature;
+	type OffchainPublic = UintAuthorityId;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TestSigner;
+	type MinBalance = ConstU32<10>;
+	type Currency = Balances;
+	type AllowanceDeposit = ConstU64<1>;
+	type AssetDeposit = ConstU64<1>;
+	// Sudo is retired, only approved proposals can make privileged calls
+	type ForceOrigin = EnsureApproved;
+	type Balance = ErcBalance;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type WeightInfo = ();
+	type Erc = Erc;
+	type PalletId = GovernancePalletId;
+	type VotingPeriod = ConstU64<10>;
+	type ProposalDeposit = ConstU32<100>;
+	type Quorum = Quorum;
+	type MaxCallLength = ConstU32<1024>;
+	type MaxProposals = ConstU32<2>;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	pallet_erc20::GenesisConfig::<Test> {
+		balances: vec![(ALICE, 2000), (BOB, 1000)],
+		..Default::default()
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+/// Proposal to issue `value` tokens to [`CHARLIE`], which only [`EnsureApproved`] may do
+fn issue_to_charlie(value: ErcBalance) -> Box<RuntimeCall> {
+	Box::new(RuntimeCall::Erc(pallet_erc20::Call::issue { to: CHARLIE, value }))
+}
+
+#[test]
+fn approved_proposal_is_dispatched_with_approved_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Erc::issue(RuntimeOrigin::root(), CHARLIE, 500),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(Governance::propose(RuntimeOrigin::signed(ALICE), issue_to_charlie(500)));
+		assert_eq!(Erc::balance_of(ALICE), 1900);
+
+		assert_ok!(Governance::vote(RuntimeOrigin::signed(ALICE), 0, true, 1200));
+		assert_ok!(Governance::vote(RuntimeOrigin::signed(BOB), 0, false, 400));
+		assert_noop!(
+			Governance::vote(RuntimeOrigin::signed(BOB), 0, false, 400),
+			Error::<Test>::AlreadyVoted
+		);
+		assert_eq!(Erc::balance_of(Governance::account_id()), 1700);
+
+		assert_noop!(
+			Governance::close(RuntimeOrigin::signed(BOB), 0, Weight::MAX),
+			Error::<Test>::VotingOngoing
+		);
+		assert_noop!(Governance::unlock(RuntimeOrigin::signed(BOB), 0), Error::<Test>::StillLocked);
+
+		// 1600 of 3000 tokens voted, quorum is half of them
+		System::set_block_number(11);
+		assert_noop!(
+			Governance::vote(RuntimeOrigin::signed(BOB), 0, true, 100),
+			Error::<Test>::VotingClosed
+		);
+		assert_ok!(Governance::close(RuntimeOrigin::signed(BOB), 0, Weight::MAX));
+		System::assert_has_event(Event::Executed { index: 0, result: Ok(()) }.into());
+
+		assert_eq!(Erc::balance_of(CHARLIE), 500);
+		assert_eq!(Erc::balance_of(ALICE), 800);
+		assert!(Governance::proposal(0).is_none());
+
+		assert_ok!(Governance::unlock(RuntimeOrigin::signed(ALICE), 0));
+		assert_ok!(Governance::unlock(RuntimeOrigin::signed(BOB), 0));
+		assert_eq!(Erc::balance_of(ALICE), 2000);
+		assert_eq!(Erc::balance_of(BOB), 1000);
+		assert_eq!(Erc::balance_of(Governance::account_id()), 0);
+	});
+}
+
+#[test]
+fn rejected_proposal_is_not_dispatched() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Governance::propose(RuntimeOrigin::signed(ALICE), issue_to_charlie(500)));
+		assert_ok!(Governance::propose(RuntimeOrigin::signed(BOB), issue_to_charlie(100)));
+		assert_noop!(
+			Governance::propose(RuntimeOrigin::signed(BOB), issue_to_charlie(100)),
+			Error::<Test>::TooManyProposals
+		);
+
+		// Majority voted for the first one, but not enough tokens voted at all
+		assert_ok!(Governance::vote(RuntimeOrigin::signed(ALICE), 0, true, 1000));
+		// Enough tokens voted on the second one, but there are no more ayes than nays
+		assert_ok!(Governance::vote(RuntimeOrigin::signed(ALICE), 1, true, 800));
+		assert_ok!(Governance::vote(RuntimeOrigin::signed(BOB), 1, false, 800));
+		assert_noop!(
+			Governance::vote(RuntimeOrigin::signed(BOB), 0, true, 5),
+			Error::<Test>::VoteTooSmall
+		);
+
+		System::set_block_number(11);
+		assert_ok!(Governance::close(RuntimeOrigin::signed(CHARLIE), 0, Weight::zero()));
+		assert_ok!(Governance::close(RuntimeOrigin::signed(CHARLIE), 1, Weight::zero()));
+		System::assert_has_event(
+			Event::Closed { index: 1, ayes: 800, nays: 800, approved: false }.into(),
+		);
+
+		// Deposits are given back either way
+		assert_eq!(Erc::balance_of(CHARLIE), 0);
+		assert_eq!(Erc::balance_of(BOB), 200);
+		assert_noop!(
+			Governance::close(RuntimeOrigin::signed(CHARLIE), 0, Weight::zero()),
+			Error::<Test>::UnknownProposal
+		);
+	});
+}
+
+#[test]
+fn paused_token_can_be_unpaused_by_proposal() {
+	new_test_ext().execute_with(|| {
+		let unpause = Box::new(RuntimeCall::Erc(pallet_erc20::Call::unpause {}));
+
+		assert_ok!(Governance::propose(RuntimeOrigin::signed(ALICE), unpause));
+		assert_ok!(Governance::vote(RuntimeOrigin::signed(ALICE), 0, true, 1500));
+		pallet_erc20::Paused::<Test>::put(true);
+
+		// Deposit can't be given back while token is paused, but that doesn't block closing
+		System::set_block_number(11);
+		assert_ok!(Governance::close(RuntimeOrigin::signed(BOB), 0, Weight::MAX));
+		System::assert_has_event(Event::Executed { index: 0, result: Ok(()) }.into());
+		assert!(!Erc::is_paused());
+
+		assert_eq!(Governance::vote_of(0, ALICE).map(|vote| vote.amount), Some(1600));
+		assert_ok!(Governance::unlock(RuntimeOrigin::signed(ALICE), 0));
+		assert_eq!(Erc::balance_of(ALICE), 2000);
+		assert_eq!(Erc::balance_of(Governance::account_id()), 0);
+	});
+}
+
+#[test]
+fn dust_left_by_vote_is_locked_along_with_it() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Governance::propose(RuntimeOrigin::signed(ALICE), issue_to_charlie(500)));
+
+		// Bob would be left with 5 tokens, which is below minimum balance
+		assert_ok!(Governance::vote(RuntimeOrigin::signed(BOB), 0, false, 995));
+		System::assert_last_event(
+			Event::Voted { index: 0, who: BOB, aye: false, amount: 1000 }.into(),
+		);
+		assert_eq!(Erc::balance_of(BOB), 0);
+		assert_eq!(Governance::proposal(0).map(|proposal| proposal.nays), Some(1000));
+
+		System::set_block_number(11);
+		assert_ok!(Governance::unlock(RuntimeOrigin::signed(BOB), 0));
+		assert_eq!(Erc::balance_of(BOB), 1000);
+	});
+}
@@ -0,0 +1,343 @@
+
+//! Weights for `pallet_erc20_governance`
+//!
+//! THESE ARE HAND-WRITTEN ESTIMATES, NOT BENCHMARK RESULTS.
+//! None of these calls were benchmarked yet, execution times are guessed from `pallet_erc20`
+//! calls doing similar work.
+//! Every call is costed by the storage it touches in its worst case:
+//! - `Measured` adds up `max_size` of every value read
+//! - `Estimated` is worked out the way the benchmark CLI does it for `MaxEncodedLen` proofs
+//! - execution time is a guess and doubles as the minimum one
+//!
+//! Run `scripts/benchmark.sh` to replace this file before relying on these weights, and before
+//! governance is trusted to replace sudo as `ForceOrigin`.
+
+// Command `scripts/benchmark.sh` regenerates this file with:
+// ./target/release/node-template
+// benchmark
+// pallet
+// --chain=dev
+// --extrinsic
+// *
+// --pallet
+// pallet_erc20_governance
+// --steps=50
+// --repeat=20
+// --wasm-execution=compiled
+// --template
+// ./pallets/frame-weight-template.hbs
+// --output
+// ./pallets/erc20-governance/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_erc20_governance`.
+pub trait WeightInfo {
+    fn propose() -> Weight;
+    fn vote() -> Weight;
+    fn close() -> Weight;
+    fn unlock() -> Weight;
+}
+
+/// Weights for `pallet_erc20_governance` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Storage: `ErcGovernance::CounterForProposals` (r:1 w:1)
+    /// Proof: `ErcGovernance::CounterForProposals` (`max_values`: Some(1), `max_size`: Some(4), mode: `MaxEncodedLen`)
+    /// Storage: `ErcGovernance::ProposalCount` (r:1 w:1)
+    /// Proof: `ErcGovernance::ProposalCount` (`max_values`: Some(1), `max_size`: Some(4), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Paused` (r:1 w:0)
+    /// Proof: `Erc::Paused` (`max_values`: Some(1), `max_size`: Some(1), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Frozen` (r:2 w:0)
+    /// Proof: `Erc::Frozen` (`max_values`: None, `max_size`: Some(48), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Vesting` (r:1 w:0)
+    /// Proof: `Erc::Vesting` (`max_values`: None, `max_size`: Some(497), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Balances` (r:2 w:2)
+    /// Proof: `Erc::Balances` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::CurrentSnapshotId` (r:1 w:0)
+    /// Proof: `Erc::CurrentSnapshotId` (`max_values`: Some(1), `max_size`: Some(4), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Delegates` (r:2 w:0)
+    /// Proof: `Erc::Delegates` (`max_values`: None, `max_size`: Some(80), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::AccountSnapshotCount` (r:2 w:2)
+    /// Proof: `Erc::AccountSnapshotCount` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::AccountSnapshots` (r:2 w:2)
+    /// Proof: `Erc::AccountSnapshots` (`max_values`: None, `max_size`: Some(68), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Votes` (r:2 w:2)
+    /// Proof: `Erc::Votes` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::NumCheckpoints` (r:2 w:2)
+    /// Proof: `Erc::NumCheckpoints` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::VoteCheckpoints` (r:2 w:2)
+    /// Proof: `Erc::VoteCheckpoints` (`max_values`: None, `max_size`: Some(68), mode: `MaxEncodedLen`)
+    /// Storage: `ErcGovernance::Proposals` (r:0 w:1)
+    /// Proof: `ErcGovernance::Proposals` (`max_values`: None, `max_size`: Some(16448), mode: `MaxEncodedLen`)
+    fn propose() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1454`
+        //  Estimated: `6100`
+        // Minimum execution time: 31_000_000 picoseconds.
+        Weight::from_parts(31_000_000, 6100)
+            .saturating_add(T::DbWeight::get().reads(21_u64))
+            .saturating_add(T::DbWeight::get().writes(15_u64))
+    }
+    /// Storage: `ErcGovernance::Votes` (r:1 w:1)
+    /// Proof: `ErcGovernance::Votes` (`max_values`: None, `max_size`: Some(69), mode: `MaxEncodedLen`)
+    /// Storage: `ErcGovernance::Proposals` (r:1 w:1)
+    /// Proof: `ErcGovernance::Proposals` (`max_values`: None, `max_size`: Some(16448), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Paused` (r:1 w:0)
+    /// Proof: `Erc::Paused` (`max_values`: Some(1), `max_size`: Some(1), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Frozen` (r:2 w:0)
+    /// Proof: `Erc::Frozen` (`max_values`: None, `max_size`: Some(48), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Vesting` (r:1 w:0)
+    /// Proof: `Erc::Vesting` (`max_values`: None, `max_size`: Some(497), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Balances` (r:2 w:2)
+    /// Proof: `Erc::Balances` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::CurrentSnapshotId` (r:1 w:0)
+    /// Proof: `Erc::CurrentSnapshotId` (`max_values`: Some(1), `max_size`: Some(4), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Delegates` (r:2 w:0)
+    /// Proof: `Erc::Delegates` (`max_values`: None, `max_size`: Some(80), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::AccountSnapshotCount` (r:2 w:2)
+    /// Proof: `Erc::AccountSnapshotCount` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::AccountSnapshots` (r:2 w:2)
+    /// Proof: `Erc::AccountSnapshots` (`max_values`: None, `max_size`: Some(68), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Votes` (r:2 w:2)
+    /// Proof: `Erc::Votes` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::NumCheckpoints` (r:2 w:2)
+    /// Proof: `Erc::NumCheckpoints` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::VoteCheckpoints` (r:2 w:2)
+    /// Proof: `Erc::VoteCheckpoints` (`max_values`: None, `max_size`: Some(68), mode: `MaxEncodedLen`)
+    fn vote() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `17963`
+        //  Estimated: `19913`
+        // Minimum execution time: 38_000_000 picoseconds.
+        Weight::from_parts(38_000_000, 19913)
+            .saturating_add(T::DbWeight::get().reads(21_u64))
+            .saturating_add(T::DbWeight::get().writes(14_u64))
+    }
+    /// Storage: `ErcGovernance::Proposals` (r:1 w:1)
+    /// Proof: `ErcGovernance::Proposals` (`max_values`: None, `max_size`: Some(16448), mode: `MaxEncodedLen`)
+    /// Storage: `ErcGovernance::Votes` (r:1 w:1)
+    /// Proof: `ErcGovernance::Votes` (`max_values`: None, `max_size`: Some(69), mode: `MaxEncodedLen`)
+    /// Storage: `ErcGovernance::CounterForProposals` (r:1 w:1)
+    /// Proof: `ErcGovernance::CounterForProposals` (`max_values`: Some(1), `max_size`: Some(4), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Paused` (r:1 w:0)
+    /// Proof: `Erc::Paused` (`max_values`: Some(1), `max_size`: Some(1), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Frozen` (r:2 w:0)
+    /// Proof: `Erc::Frozen` (`max_values`: None, `max_size`: Some(48), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Vesting` (r:1 w:0)
+    /// Proof: `Erc::Vesting` (`max_values`: None, `max_size`: Some(497), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Balances` (r:2 w:2)
+    /// Proof: `Erc::Balances` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::CurrentSnapshotId` (r:1 w:0)
+    /// Proof: `Erc::CurrentSnapshotId` (`max_values`: Some(1), `max_size`: Some(4), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Delegates` (r:2 w:0)
+    /// Proof: `Erc::Delegates` (`max_values`: None, `max_size`: Some(80), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::AccountSnapshotCount` (r:2 w:2)
+    /// Proof: `Erc::AccountSnapshotCount` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::AccountSnapshots` (r:2 w:2)
+    /// Proof: `Erc::AccountSnapshots` (`max_values`: None, `max_size`: Some(68), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Votes` (r:2 w:2)
+    /// Proof: `Erc::Votes` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::NumCheckpoints` (r:2 w:2)
+    /// Proof: `Erc::NumCheckpoints` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::VoteCheckpoints` (r:2 w:2)
+    /// Proof: `Erc::VoteCheckpoints` (`max_values`: None, `max_size`: Some(68), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::TotalSupply` (r:1 w:0)
+    /// Proof: `Erc::TotalSupply` (`max_values`: Some(1), `max_size`: Some(4), mode: `MaxEncodedLen`)
+    fn close() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `17971`
+        //  Estimated: `19913`
+        // Minimum execution time: 42_000_000 picoseconds.
+        Weight::from_parts(42_000_000, 19913)
+            .saturating_add(T::DbWeight::get().reads(23_u64))
+            .saturating_add(T::DbWeight::get().writes(15_u64))
+    }
+    /// Storage: `ErcGovernance::Votes` (r:1 w:1)
+    /// Proof: `ErcGovernance::Votes` (`max_values`: None, `max_size`: Some(69), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Paused` (r:1 w:0)
+    /// Proof: `Erc::Paused` (`max_values`: Some(1), `max_size`: Some(1), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Frozen` (r:2 w:0)
+    /// Proof: `Erc::Frozen` (`max_values`: None, `max_size`: Some(48), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Vesting` (r:1 w:0)
+    /// Proof: `Erc::Vesting` (`max_values`: None, `max_size`: Some(497), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Balances` (r:2 w:2)
+    /// Proof: `Erc::Balances` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::CurrentSnapshotId` (r:1 w:0)
+    /// Proof: `Erc::CurrentSnapshotId` (`max_values`: Some(1), `max_size`: Some(4), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Delegates` (r:2 w:0)
+    /// Proof: `Erc::Delegates` (`max_values`: None, `max_size`: Some(80), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::AccountSnapshotCount` (r:2 w:2)
+    /// Proof: `Erc::AccountSnapshotCount` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::AccountSnapshots` (r:2 w:2)
+    /// Proof: `Erc::AccountSnapshots` (`max_values`: None, `max_size`: Some(68), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Votes` (r:2 w:2)
+    /// Proof: `Erc::Votes` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::NumCheckpoints` (r:2 w:2)
+    /// Proof: `Erc::NumCheckpoints` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::VoteCheckpoints` (r:2 w:2)
+    /// Proof: `Erc::VoteCheckpoints` (`max_values`: None, `max_size`: Some(68), mode: `MaxEncodedLen`)
+    fn unlock() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1515`
+        //  Estimated: `6100`
+        // Minimum execution time: 22_000_000 picoseconds.
+        Weight::from_parts(22_000_000, 6100)
+            .saturating_add(T::DbWeight::get().reads(20_u64))
+            .saturating_add(T::DbWeight::get().writes(13_u64))
+    }
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    /// Storage: `ErcGovernance::CounterForProposals` (r:1 w:1)
+    /// Proof: `ErcGovernance::CounterForProposals` (`max_values`: Some(1), `max_size`: Some(4), mode: `MaxEncodedLen`)
+    /// Storage: `ErcGovernance::ProposalCount` (r:1 w:1)
+    /// Proof: `ErcGovernance::ProposalCount` (`max_values`: Some(1), `max_size`: Some(4), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Paused` (r:1 w:0)
+    /// Proof: `Erc::Paused` (`max_values`: Some(1), `max_size`: Some(1), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Frozen` (r:2 w:0)
+    /// Proof: `Erc::Frozen` (`max_values`: None, `max_size`: Some(48), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Vesting` (r:1 w:0)
+    /// Proof: `Erc::Vesting` (`max_values`: None, `max_size`: Some(497), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Balances` (r:2 w:2)
+    /// Proof: `Erc::Balances` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::CurrentSnapshotId` (r:1 w:0)
+    /// Proof: `Erc::CurrentSnapshotId` (`max_values`: Some(1), `max_size`: Some(4), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Delegates` (r:2 w:0)
+    /// Proof: `Erc::Delegates` (`max_values`: None, `max_size`: Some(80), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::AccountSnapshotCount` (r:2 w:2)
+    /// Proof: `Erc::AccountSnapshotCount` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::AccountSnapshots` (r:2 w:2)
+    /// Proof: `Erc::AccountSnapshots` (`max_values`: None, `max_size`: Some(68), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Votes` (r:2 w:2)
+    /// Proof: `Erc::Votes` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::NumCheckpoints` (r:2 w:2)
+    /// Proof: `Erc::NumCheckpoints` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::VoteCheckpoints` (r:2 w:2)
+    /// Proof: `Erc::VoteCheckpoints` (`max_values`: None, `max_size`: Some(68), mode: `MaxEncodedLen`)
+    /// Storage: `ErcGovernance::Proposals` (r:0 w:1)
+    /// Proof: `ErcGovernance::Proposals` (`max_values`: None, `max_size`: Some(16448), mode: `MaxEncodedLen`)
+    fn propose() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1454`
+        //  Estimated: `6100`
+        // Minimum execution time: 31_000_000 picoseconds.
+        Weight::from_parts(31_000_000, 6100)
+            .saturating_add(RocksDbWeight::get().reads(21_u64))
+            .saturating_add(RocksDbWeight::get().writes(15_u64))
+    }
+    /// Storage: `ErcGovernance::Votes` (r:1 w:1)
+    /// Proof: `ErcGovernance::Votes` (`max_values`: None, `max_size`: Some(69), mode: `MaxEncodedLen`)
+    /// Storage: `ErcGovernance::Proposals` (r:1 w:1)
+    /// Proof: `ErcGovernance::Proposals` (`max_values`: None, `max_size`: Some(16448), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Paused` (r:1 w:0)
+    /// Proof: `Erc::Paused` (`max_values`: Some(1), `max_size`: Some(1), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Frozen` (r:2 w:0)
+    /// Proof: `Erc::Frozen` (`max_values`: None, `max_size`: Some(48), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Vesting` (r:1 w:0)
+    /// Proof: `Erc::Vesting` (`max_values`: None, `max_size`: Some(497), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Balances` (r:2 w:2)
+    /// Proof: `Erc::Balances` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::CurrentSnapshotId` (r:1 w:0)
+    /// Proof: `Erc::CurrentSnapshotId` (`max_values`: Some(1), `max_size`: Some(4), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Delegates` (r:2 w:0)
+    /// Proof: `Erc::Delegates` (`max_values`: None, `max_size`: Some(80), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::AccountSnapshotCount` (r:2 w:2)
+    /// Proof: `Erc::AccountSnapshotCount` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::AccountSnapshots` (r:2 w:2)
+    /// Proof: `Erc::AccountSnapshots` (`max_values`: None, `max_size`: Some(68), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Votes` (r:2 w:2)
+    /// Proof: `Erc::Votes` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::NumCheckpoints` (r:2 w:2)
+    /// Proof: `Erc::NumCheckpoints` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::VoteCheckpoints` (r:2 w:2)
+    /// Proof: `Erc::VoteCheckpoints` (`max_values`: None, `max_size`: Some(68), mode: `MaxEncodedLen`)
+    fn vote() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `17963`
+        //  Estimated: `19913`
+        // Minimum execution time: 38_000_000 picoseconds.
+        Weight::from_parts(38_000_000, 19913)
+            .saturating_add(RocksDbWeight::get().reads(21_u64))
+            .saturating_add(RocksDbWeight::get().writes(14_u64))
+    }
+    /// Storage: `ErcGovernance::Proposals` (r:1 w:1)
+    /// Proof: `ErcGovernance::Proposals` (`max_values`: None, `max_size`: Some(16448), mode: `MaxEncodedLen`)
+    /// Storage: `ErcGovernance::Votes` (r:1 w:1)
+    /// Proof: `ErcGovernance::Votes` (`max_values`: None, `max_size`: Some(69), mode: `MaxEncodedLen`)
+    /// Storage: `ErcGovernance::CounterForProposals` (r:1 w:1)
+    /// Proof: `ErcGovernance::CounterForProposals` (`max_values`: Some(1), `max_size`: Some(4), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Paused` (r:1 w:0)
+    /// Proof: `Erc::Paused` (`max_values`: Some(1), `max_size`: Some(1), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Frozen` (r:2 w:0)
+    /// Proof: `Erc::Frozen` (`max_values`: None, `max_size`: Some(48), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Vesting` (r:1 w:0)
+    /// Proof: `Erc::Vesting` (`max_values`: None, `max_size`: Some(497), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Balances` (r:2 w:2)
+    /// Proof: `Erc::Balances` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::CurrentSnapshotId` (r:1 w:0)
+    /// Proof: `Erc::CurrentSnapshotId` (`max_values`: Some(1), `max_size`: Some(4), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Delegates` (r:2 w:0)
+    /// Proof: `Erc::Delegates` (`max_values`: None, `max_size`: Some(80), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::AccountSnapshotCount` (r:2 w:2)
+    /// Proof: `Erc::AccountSnapshotCount` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::AccountSnapshots` (r:2 w:2)
+    /// Proof: `Erc::AccountSnapshots` (`max_values`: None, `max_size`: Some(68), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Votes` (r:2 w:2)
+    /// Proof: `Erc::Votes` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::NumCheckpoints` (r:2 w:2)
+    /// Proof: `Erc::NumCheckpoints` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::VoteCheckpoints` (r:2 w:2)
+    /// Proof: `Erc::VoteCheckpoints` (`max_values`: None, `max_size`: Some(68), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::TotalSupply` (r:1 w:0)
+    /// Proof: `Erc::TotalSupply` (`max_values`: Some(1), `max_size`: Some(4), mode: `MaxEncodedLen`)
+    fn close() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `17971`
+        //  Estimated: `19913`
+        // Minimum execution time: 42_000_000 picoseconds.
+        Weight::from_parts(42_000_000, 19913)
+            .saturating_add(RocksDbWeight::get().reads(23_u64))
+            .saturating_add(RocksDbWeight::get().writes(15_u64))
+    }
+    /// Storage: `ErcGovernance::Votes` (r:1 w:1)
+    /// Proof: `ErcGovernance::Votes` (`max_values`: None, `max_size`: Some(69), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Paused` (r:1 w:0)
+    /// Proof: `Erc::Paused` (`max_values`: Some(1), `max_size`: Some(1), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Frozen` (r:2 w:0)
+    /// Proof: `Erc::Frozen` (`max_values`: None, `max_size`: Some(48), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Vesting` (r:1 w:0)
+    /// Proof: `Erc::Vesting` (`max_values`: None, `max_size`: Some(497), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Balances` (r:2 w:2)
+    /// Proof: `Erc::Balances` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::CurrentSnapshotId` (r:1 w:0)
+    /// Proof: `Erc::CurrentSnapshotId` (`max_values`: Some(1), `max_size`: Some(4), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Delegates` (r:2 w:0)
+    /// Proof: `Erc::Delegates` (`max_values`: None, `max_size`: Some(80), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::AccountSnapshotCount` (r:2 w:2)
+    /// Proof: `Erc::AccountSnapshotCount` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::AccountSnapshots` (r:2 w:2)
+    /// Proof: `Erc::AccountSnapshots` (`max_values`: None, `max_size`: Some(68), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::Votes` (r:2 w:2)
+    /// Proof: `Erc::Votes` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::NumCheckpoints` (r:2 w:2)
+    /// Proof: `Erc::NumCheckpoints` (`max_values`: None, `max_size`: Some(52), mode: `MaxEncodedLen`)
+    /// Storage: `Erc::VoteCheckpoints` (r:2 w:2)
+    /// Proof: `Erc::VoteCheckpoints` (`max_values`: None, `max_size`: Some(68), mode: `MaxEncodedLen`)
+    fn unlock() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1515`
+        //  Estimated: `6100`
+        // Minimum execution time: 22_000_000 picoseconds.
+        Weight::from_parts(22_000_000, 6100)
+            .saturating_add(RocksDbWeight::get().reads(20_u64))
+            .saturating_add(RocksDbWeight::get().writes(13_u64))
+    }
+}
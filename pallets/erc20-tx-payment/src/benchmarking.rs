@@ -0,0 +1,27 @@
+//! Benchmarking setup for pallet-erc20-tx-payment
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_conversion_rate() {
+		let rate = Some(FixedU128::from_rational(1, 2));
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, rate);
+
+		assert_eq!(Pallet::<T>::conversion_rate(), rate);
+	}
+
+	impl_benchmark_test_suite! {
+		Pallet,
+		tests::new_test_ext(),
+		tests::Test,
+	}
+}
@@ -0,0 +1,317 @@
+//! Lets signers pay transaction fees with Erc tokens instead of native currency.
+//!
+//! [`ChargeErcTransactionPayment`] replaces `pallet_transaction_payment::ChargeTransactionPayment`
+//! in runtime's `SignedExtra`. Fee is still computed by `pallet_transaction_payment`, so
+//! `TransactionPaymentApi` keeps estimating it as usual. When signer sets `pay_in_erc` that fee is
+//! converted into Erc tokens at [`ConversionRate`] and sent to [`Config::FeeCollector`], otherwise
+//! it's charged in native currency exactly like `ChargeTransactionPayment` does
+
+// All pallets have to be like this because we're compiling for WebAssembly target
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+pub use weights::*;
+
+use codec::{Decode, Encode};
+use frame_support::{
+	dispatch::{DispatchInfo, DispatchResult, PostDispatchInfo},
+	traits::{
+		fungible::{Inspect, Mutate},
+		tokens::{DepositConsequence, Preservation, Provenance, WithdrawConsequence},
+	},
+};
+use pallet_transaction_payment::{ChargeTransactionPayment, OnChargeTransaction};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{
+		DispatchInfoOf, Dispatchable, PostDispatchInfoOf, Saturating, SignedExtension,
+		UniqueSaturatedFrom, UniqueSaturatedInto, Zero,
+	},
+	transaction_validity::{
+		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
+	FixedPointNumber, FixedPointOperand, FixedU128, Rounding,
+};
+
+/// Native balance type fees are computed in
+pub type BalanceOf<T> = <<T as pallet_transaction_payment::Config>::OnChargeTransaction as OnChargeTransaction<T>>::Balance;
+
+/// Balance type of Erc tokens fees are paid with
+pub type ErcBalanceOf<T> =
+	<<T as Config>::Erc as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Whatever native payment handler keeps between `pre_dispatch` and `post_dispatch` to refund
+type LiquidityInfoOf<T> = <<T as pallet_transaction_payment::Config>::OnChargeTransaction as OnChargeTransaction<T>>::LiquidityInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_transaction_payment::Config {
+		type RuntimeEvent: From<Event<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Erc instance fees can be paid with
+		type Erc: Mutate<Self::AccountId>;
+
+		/// Account that receives fees paid in Erc tokens
+		#[pallet::constant]
+		type FeeCollector: Get<Self::AccountId>;
+
+		/// The origin that's allowed to set conversion rate. In real situation this will be either
+		/// Root or Sudo call
+		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+	}
+
+	/// How many Erc tokens are charged per unit of native fee. Paying in Erc is disabled until
+	/// it's set
+	#[pallet::storage]
+	#[pallet::getter(fn conversion_rate)]
+	pub type ConversionRate<T: Config> = StorageValue<_, FixedU128, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub (super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Fee was paid in Erc tokens, `tip` is in native currency as it was signed
+		ErcFeePaid { who: T::AccountId, actual_fee: ErcBalanceOf<T>, tip: BalanceOf<T> },
+		ConversionRateSet { rate: Option<FixedU128> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Rate of zero would make fees paid in Erc free, `None` disables paying in Erc instead
+		ZeroConversionRate,
+	}
+
+	#[pallet::call(weight(<T as Config>::WeightInfo))]
+	impl<T: Config> Pallet<T> {
+		/// Sets how many Erc tokens are charged per unit of native fee, `None` disables paying in
+		/// Erc
+		#[pallet::call_index(0)]
+		pub fn set_conversion_rate(origin: OriginFor<T>, rate: Option<FixedU128>) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			ensure!(rate.map_or(true, |rate| !rate.is_zero()), Error::<T>::ZeroConversionRate);
+
+			<ConversionRate<T>>::set(rate);
+
+			Self::deposit_event(Event::ConversionRateSet { rate });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Converts native `fee` into Erc tokens at current rate, `None` if paying in Erc is
+		/// disabled. Rounds up, so fee worth less than one Erc token still costs one
+		pub fn to_erc(fee: BalanceOf<T>) -> Option<ErcBalanceOf<T>> {
+			let rate = Self::conversion_rate()?;
+			let fee: u128 = fee.unique_saturated_into();
+			let erc_fee = multiply_by_rational_with_rounding(
+				fee,
+				rate.into_inner(),
+				FixedU128::DIV,
+				Rounding::Up,
+			)
+			.unwrap_or(u128::MAX);
+
+			Some(ErcBalanceOf::<T>::unique_saturated_from(erc_fee))
+		}
+	}
+}
+
+/// What was taken from the signer before dispatch
+pub enum InitialPayment<T: Config> {
+	/// Fee was charged in native currency by `ChargeTransactionPayment`
+	Native(LiquidityInfoOf<T>),
+	/// Fee was paid with that many Erc tokens
+	Erc(ErcBalanceOf<T>),
+	/// Fee turned out to be zero
+	Nothing,
+}
+
+/// Replacement of `ChargeTransactionPayment` that charges fee in Erc tokens if `pay_in_erc` is set
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeErcTransactionPayment<T: Config> {
+	#[codec(compact)]
+	tip: BalanceOf<T>,
+	pay_in_erc: bool,
+}
+
+impl<T: Config> ChargeErcTransactionPayment<T>
+where
+	T::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	BalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
+{
+	pub fn new(tip: BalanceOf<T>, pay_in_erc: bool) -> Self {
+		Self { tip, pay_in_erc }
+	}
+
+	/// Computes native fee along with its Erc equivalent and makes sure `who` can afford it and
+	/// [`Config::FeeCollector`] can receive it
+	fn erc_fee(
+		&self,
+		who: &T::AccountId,
+		info: &DispatchInfoOf<T::RuntimeCall>,
+		len: usize,
+	) -> Result<(BalanceOf<T>, ErcBalanceOf<T>), TransactionValidityError> {
+		let fee = pallet_transaction_payment::Pallet::<T>::compute_fee(len as u32, info, self.tip);
+		let erc_fee = Pallet::<T>::to_erc(fee).ok_or(InvalidTransaction::Payment)?;
+
+		// Only zero fee converts into zero, unless the rate itself was zeroed in storage
+		if erc_fee.is_zero() {
+			if !fee.is_zero() {
+				return Err(InvalidTransaction::Payment.into())
+			}
+
+			return Ok((fee, erc_fee))
+		}
+
+		if T::Erc::can_withdraw(who, erc_fee) != WithdrawConsequence::Success {
+			return Err(InvalidTransaction::Payment.into())
+		}
+
+		// Collector without tokens can't be given less than `MinBalance`, so such fee would only
+		// fail later in `pre_dispatch`
+		if T::Erc::can_deposit(&T::FeeCollector::get(), erc_fee, Provenance::Extant) !=
+			DepositConsequence::Success
+		{
+			return Err(InvalidTransaction::Payment.into())
+		}
+
+		Ok((fee, erc_fee))
+	}
+}
+
+impl<T: Config> sp_std::fmt::Debug for ChargeErcTransactionPayment<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "ChargeErcTransactionPayment<{:?}, {}>", self.tip, self.pay_in_erc)
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config> SignedExtension for ChargeErcTransactionPayment<T>
+where
+	T::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	BalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
+{
+	const IDENTIFIER: &'static str = "ChargeErcTransactionPayment";
+	type AccountId = T::AccountId;
+	type Call = T::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = (BalanceOf<T>, Self::AccountId, InitialPayment<T>);
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		if !self.pay_in_erc {
+			return ChargeTransactionPayment::<T>::from(self.tip).validate(who, call, info, len)
+		}
+
+		let (fee, _) = self.erc_fee(who, info, len)?;
+		let priority = ChargeTransactionPayment::<T>::get_priority(info, len, self.tip, fee);
+
+		Ok(ValidTransaction { priority, ..Default::default() })
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		if !self.pay_in_erc {
+			let (tip, who, liquidity) =
+				ChargeTransactionPayment::<T>::from(self.tip).pre_dispatch(who, call, info, len)?;
+
+			return Ok((tip, who, InitialPayment::Native(liquidity)))
+		}
+
+		let (_, erc_fee) = self.erc_fee(who, info, len)?;
+
+		if erc_fee.is_zero() {
+			return Ok((self.tip, who.clone(), InitialPayment::Nothing))
+		}
+
+		T::Erc::transfer(who, &T::FeeCollector::get(), erc_fee, Preservation::Expendable)
+			.map_err(|_| InvalidTransaction::Payment)?;
+
+		Ok((self.tip, who.clone(), InitialPayment::Erc(erc_fee)))
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		let Some((tip, who, initial_payment)) = pre else { return Ok(()) };
+
+		match initial_payment {
+			InitialPayment::Native(liquidity) => ChargeTransactionPayment::<T>::post_dispatch(
+				Some((tip, who, liquidity)),
+				info,
+				post_info,
+				len,
+				result,
+			),
+			InitialPayment::Erc(paid) => {
+				let actual_fee = pallet_transaction_payment::Pallet::<T>::compute_actual_fee(
+					len as u32, info, post_info, tip,
+				);
+
+				// Rate may have been changed by the call itself, never charge more than was taken
+				let mut actual_fee = Pallet::<T>::to_erc(actual_fee).unwrap_or(paid).min(paid);
+				let refund = paid.saturating_sub(actual_fee);
+
+				// Refund only fails if it would reap the collector or token got paused, in which
+				// case the whole fee is kept rather than failing already dispatched transaction
+				if !refund.is_zero() &&
+					T::Erc::transfer(&T::FeeCollector::get(), &who, refund, Preservation::Preserve)
+						.is_err()
+				{
+					actual_fee = paid;
+				}
+
+				Pallet::<T>::deposit_event(Event::ErcFeePaid { who, actual_fee, tip });
+
+				Ok(())
+			},
+			InitialPayment::Nothing => Ok(()),
+		}
+	}
+}
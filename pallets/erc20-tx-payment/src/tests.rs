@@ -0,0 +1,275 @@
+use crate::{self as pallet_erc20_tx_payment, ChargeErcTransactionPayment, Config, Error};
+use frame_support::{
+	assert_noop, assert_ok, derive_impl,
+	dispatch::{DispatchClass, DispatchInfo, PostDispatchInfo},
+	parameter_types,
+	traits::{ConstU32, ConstU64, ConstU8},
+	weights::{IdentityFee, Weight},
+};
+use frame_system::limits;
+use pallet_transaction_payment::CurrencyAdapter;
+use sp_runtime::{
+	testing::{TestSignature, UintAuthorityId},
+	traits::{SignedExtension, Zero},
+	BuildStorage, FixedU128,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+pub type ErcBalance = u32;
+
+pub const ALICE: u64 = 1;
+pub const FEE_COLLECTOR: u64 = 42;
+
+parameter_types! {
+	pub const FeeCollector: u64 = FEE_COLLECTOR;
+	// No base extrinsic weight, so fee is exactly dispatch weight plus length
+	pub BlockWeights: limits::BlockWeights = limits::BlockWeights::builder()
+		.base_block(Weight::zero())
+		.for_class(DispatchClass::all(), |weights| weights.base_extrinsic = Weight::zero())
+		.for_class(DispatchClass::non_mandatory(), |weights| {
+			weights.max_total = Some(Weight::from_parts(1024, u64::MAX))
+		})
+		.build_or_panic();
+}
+
+/// Only needed to satisfy `pallet_erc20::Config`, permits aren't benchmarked here
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TestSigner;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_erc20::BenchmarkHelper<u64, UintAuthorityId, TestSignature> for TestSigner {
+	fn signer() -> (UintAuthorityId, u64) {
+		(UintAuthorityId(1), 1)
+	}
+
+	fn sign(signer: &UintAuthorityId, message: &[u8]) -> TestSignature {
+		TestSignature(signer.0, message.to_vec())
+	}
+}
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		TransactionPayment: pallet_transaction_payment,
+		Sudo: pallet_sudo,
+		Erc: pallet_erc20,
+		ErcTxPayment: pallet_erc20_tx_payment,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type BlockWeights = BlockWeights;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+impl pallet_transaction_payment::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type OnChargeTransaction = CurrencyAdapter<Balances, ()>;
+	type OperationalFeeMultiplier = ConstU8<5>;
+	type WeightToFee = IdentityFee<u64>;
+	type LengthToFee = IdentityFee<u64>;
+	type FeeMultiplierUpdate = ();
+}
+
+#[derive_impl(pallet_sudo::config_preludes::TestDefaultConfig)]
+impl pallet_sudo::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+}
+
+impl pallet_erc20::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Supply = ConstU32<{ u32::MAX }>;
+	type MaxNameLength = ConstU8<50>;
+	type MaxSymbolLength = ConstU8<50>;
+	type MaxUriLength = ConstU32<128>;
+	type MaxDescriptionLength = ConstU32<256>;
+	type MaxBatchTransfers = ConstU32<128>;
+	type MaxVestingSchedules = ConstU32<28>;
+	type MinVestedTransfer = ConstU32<100>;
+	type OnTransfer = ();
+	type BeforeTransfer = ();
+	type OffchainSignature = TestSignature;
+	type OffchainPublic = UintAuthorityId;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TestSigner;
+	type MinBalance = ConstU32<10>;
+	type Currency = Balances;
+	type AllowanceDeposit = ConstU64<1>;
+	type AssetDeposit = ConstU64<1>;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type Balance = ErcBalance;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Erc = Erc;
+	type FeeCollector = FeeCollector;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	pallet_erc20::GenesisConfig::<Test> {
+		balances: vec![(ALICE, 2000)],
+		..Default::default()
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+const CALL: &RuntimeCall = &RuntimeCall::System(frame_system::Call::remark { remark: Vec::new() });
+
+fn info(weight: u64) -> DispatchInfo {
+	DispatchInfo { weight: Weight::from_parts(weight, 0), ..Default::default() }
+}
+
+fn post_info(weight: u64) -> PostDispatchInfo {
+	PostDispatchInfo {
+		actual_weight: Some(Weight::from_parts(weight, 0)),
+		pays_fee: Default::default(),
+	}
+}
+
+#[test]
+fn fee_is_paid_in_erc_and_unused_part_is_refunded() {
+	new_test_ext().execute_with(|| {
+		let who = ALICE;
+
+		assert_ok!(ErcTxPayment::set_conversion_rate(
+			RuntimeOrigin::root(),
+			Some(FixedU128::from_rational(1, 2))
+		));
+
+		// Signer has no native funds at all, fee is `100 + 10` length, halved by conversion
+		let pre = ChargeErcTransactionPayment::<Test>::new(0, true)
+			.pre_dispatch(&who, CALL, &info(100), 10)
+			.unwrap();
+
+		assert_eq!(Erc::balance_of(who), 1945);
+		assert_eq!(Erc::balance_of(FEE_COLLECTOR), 55);
+
+		assert_ok!(ChargeErcTransactionPayment::<Test>::post_dispatch(
+			Some(pre),
+			&info(100),
+			&post_info(50),
+			10,
+			&Ok(())
+		));
+
+		assert_eq!(Erc::balance_of(who), 1970);
+		assert_eq!(Erc::balance_of(FEE_COLLECTOR), 30);
+	});
+}
+
+#[test]
+fn paying_in_erc_requires_conversion_rate() {
+	new_test_ext().execute_with(|| {
+		let who = ALICE;
+
+		assert!(ChargeErcTransactionPayment::<Test>::new(0, true)
+			.validate(&who, CALL, &info(100), 10)
+			.is_err());
+
+		// Without `pay_in_erc` native fee is charged and signer has nothing to pay it with
+		assert!(ChargeErcTransactionPayment::<Test>::new(0, false)
+			.validate(&who, CALL, &info(100), 10)
+			.is_err());
+	});
+}
+
+#[test]
+fn fee_below_min_balance_needs_funded_collector() {
+	new_test_ext().execute_with(|| {
+		let who = ALICE;
+
+		assert_ok!(ErcTxPayment::set_conversion_rate(
+			RuntimeOrigin::root(),
+			Some(FixedU128::from_rational(1, 2))
+		));
+
+		// Fee of `5` would leave empty collector below `MinBalance`
+		assert!(ChargeErcTransactionPayment::<Test>::new(0, true)
+			.validate(&who, CALL, &info(10), 0)
+			.is_err());
+
+		assert_ok!(Erc::transfer(RuntimeOrigin::signed(who), FEE_COLLECTOR, 10));
+
+		let payment = ChargeErcTransactionPayment::<Test>::new(0, true);
+		assert_ok!(payment.validate(&who, CALL, &info(10), 0));
+		assert_ok!(payment.pre_dispatch(&who, CALL, &info(10), 0));
+		assert_eq!(Erc::balance_of(FEE_COLLECTOR), 15);
+	});
+}
+
+#[test]
+fn converted_fee_is_rounded_up() {
+	new_test_ext().execute_with(|| {
+		let who = ALICE;
+
+		assert_ok!(ErcTxPayment::set_conversion_rate(
+			RuntimeOrigin::root(),
+			Some(FixedU128::from_rational(1, 3))
+		));
+		assert_ok!(Erc::transfer(RuntimeOrigin::signed(who), FEE_COLLECTOR, 10));
+
+		// A third of `10` is charged as `4` rather than `3`
+		assert_eq!(ErcTxPayment::to_erc(10), Some(4));
+		let payment = ChargeErcTransactionPayment::<Test>::new(0, true);
+		assert_ok!(payment.pre_dispatch(&who, CALL, &info(10), 0));
+		assert_eq!(Erc::balance_of(FEE_COLLECTOR), 14);
+
+		// Fee worth a fraction of a token still costs a whole one
+		assert_ok!(ErcTxPayment::set_conversion_rate(
+			RuntimeOrigin::root(),
+			Some(FixedU128::from_rational(1, 1000))
+		));
+		assert_eq!(ErcTxPayment::to_erc(1), Some(1));
+		assert_eq!(ErcTxPayment::to_erc(0), Some(0));
+	});
+}
+
+#[test]
+fn zero_conversion_rate_is_rejected() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ErcTxPayment::set_conversion_rate(RuntimeOrigin::root(), Some(FixedU128::zero())),
+			Error::<Test>::ZeroConversionRate
+		);
+	});
+}
+
+#[test]
+fn nonzero_fee_is_never_free_in_erc() {
+	new_test_ext().execute_with(|| {
+		let who = ALICE;
+
+		// Rate that made it into storage some other way converts every fee into nothing
+		pallet_erc20_tx_payment::ConversionRate::<Test>::put(FixedU128::zero());
+
+		assert!(ChargeErcTransactionPayment::<Test>::new(0, true)
+			.validate(&who, CALL, &info(100), 10)
+			.is_err());
+		assert!(ChargeErcTransactionPayment::<Test>::new(0, true)
+			.pre_dispatch(&who, CALL, &info(100), 10)
+			.is_err());
+		assert_eq!(Erc::balance_of(who), 2000);
+	});
+}
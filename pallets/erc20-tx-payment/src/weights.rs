@@ -0,0 +1,71 @@
+
+//! Weights for `pallet_erc20_tx_payment`
+//!
+//! THESE ARE HAND-WRITTEN ESTIMATES, NOT BENCHMARK RESULTS.
+//! None of these calls were benchmarked yet, execution times are guessed from `pallet_erc20`
+//! calls doing similar work.
+//! Every call is costed by the storage it touches in its worst case:
+//! - `Measured` adds up `max_size` of every value read
+//! - `Estimated` is worked out the way the benchmark CLI does it for `MaxEncodedLen` proofs
+//! - execution time is a guess and doubles as the minimum one
+//!
+//! Run `scripts/benchmark.sh` to replace this file before relying on these weights.
+
+// Command `scripts/benchmark.sh` regenerates this file with:
+// ./target/release/node-template
+// benchmark
+// pallet
+// --chain=dev
+// --extrinsic
+// *
+// --pallet
+// pallet_erc20_tx_payment
+// --steps=50
+// --repeat=20
+// --wasm-execution=compiled
+// --template
+// ./pallets/frame-weight-template.hbs
+// --output
+// ./pallets/erc20-tx-payment/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_erc20_tx_payment`.
+pub trait WeightInfo {
+    fn set_conversion_rate() -> Weight;
+}
+
+/// Weights for `pallet_erc20_tx_payment` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Storage: `ErcTxPayment::ConversionRate` (r:0 w:1)
+    /// Proof: `ErcTxPayment::ConversionRate` (`max_values`: Some(1), `max_size`: Some(16), mode: `MaxEncodedLen`)
+    fn set_conversion_rate() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `0`
+        //  Estimated: `0`
+        // Minimum execution time: 4_000_000 picoseconds.
+        Weight::from_parts(4_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    /// Storage: `ErcTxPayment::ConversionRate` (r:0 w:1)
+    /// Proof: `ErcTxPayment::ConversionRate` (`max_values`: Some(1), `max_size`: Some(16), mode: `MaxEncodedLen`)
+    fn set_conversion_rate() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `0`
+        //  Estimated: `0`
+        // Minimum execution time: 4_000_000 picoseconds.
+        Weight::from_parts(4_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+}
@@ -0,0 +1,130 @@
+//! RPC interface for the ERC20 pallet. Exposes `erc20_*` methods that are backed by
+//! [`Erc20Api`](pallet_erc20::runtime_api::Erc20Api) runtime API
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use serde::{de::DeserializeOwned, Serialize};
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_erc20::runtime_api::Erc20Api as Erc20RuntimeApi;
+
+#[rpc(client, server)]
+pub trait Erc20Api<BlockHash, AccountId, Balance> {
+	#[method(name = "erc20_balanceOf")]
+	fn balance_of(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<Balance>;
+
+	#[method(name = "erc20_allowance")]
+	fn allowance(
+		&self,
+		owner: AccountId,
+		spender: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Balance>;
+
+	#[method(name = "erc20_totalSupply")]
+	fn total_supply(&self, at: Option<BlockHash>) -> RpcResult<Balance>;
+
+	#[method(name = "erc20_name")]
+	fn name(&self, at: Option<BlockHash>) -> RpcResult<Bytes>;
+
+	#[method(name = "erc20_symbol")]
+	fn symbol(&self, at: Option<BlockHash>) -> RpcResult<Bytes>;
+
+	#[method(name = "erc20_decimals")]
+	fn decimals(&self, at: Option<BlockHash>) -> RpcResult<u8>;
+}
+
+/// Provides RPC methods to query ERC20 token state
+pub struct Erc20<C, Block> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> Erc20<C, Block> {
+	/// Creates new instance of ERC20 RPC handler
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api
+pub enum Error {
+	/// Call to the runtime API has failed
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+fn runtime_error(e: impl std::fmt::Debug) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query ERC20 state.",
+		Some(format!("{:?}", e)),
+	)
+}
+
+impl<C, Block, AccountId, Balance> Erc20ApiServer<<Block as BlockT>::Hash, AccountId, Balance>
+	for Erc20<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: Erc20RuntimeApi<Block, AccountId, Balance>,
+	AccountId: Codec + DeserializeOwned + Send + Sync + 'static,
+	Balance: Codec + Serialize + Send + Sync + 'static,
+{
+	fn balance_of(&self, who: AccountId, at: Option<Block::Hash>) -> RpcResult<Balance> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client.runtime_api().balance_of(at, who).map_err(runtime_error)
+	}
+
+	fn allowance(
+		&self,
+		owner: AccountId,
+		spender: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Balance> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client.runtime_api().allowance(at, owner, spender).map_err(runtime_error)
+	}
+
+	fn total_supply(&self, at: Option<Block::Hash>) -> RpcResult<Balance> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client.runtime_api().total_supply(at).map_err(runtime_error)
+	}
+
+	fn name(&self, at: Option<Block::Hash>) -> RpcResult<Bytes> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client.runtime_api().name(at).map(Into::into).map_err(runtime_error)
+	}
+
+	fn symbol(&self, at: Option<Block::Hash>) -> RpcResult<Bytes> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client.runtime_api().symbol(at).map(Into::into).map_err(runtime_error)
+	}
+
+	fn decimals(&self, at: Option<Block::Hash>) -> RpcResult<u8> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client.runtime_api().decimals(at).map_err(runtime_error)
+	}
+}
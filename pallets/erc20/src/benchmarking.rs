@@ -3,17 +3,83 @@ use super::*;
 
 use super::Pallet as Erc;
 use frame_benchmarking::v2::*;
-use frame_system::RawOrigin;
+use frame_support::{
+	traits::{tokens::Preservation, Currency, Get},
+	BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::{traits::Bounded, Saturating};
+use sp_std::{vec, vec::Vec};
 
 const SEED: u32 = 0;
 
+/// Moving tokens is most expensive right after a snapshot, when balances have to be copied into
+/// history before they change
+fn take_snapshot<T: Config<I>, I: 'static>() {
+	CurrentSnapshotId::<T, I>::put(1);
+}
+
+/// Balance changes are most expensive when the accounts delegated their votes, since their
+/// delegates get new checkpoints too
+fn delegate_votes<T: Config<I>, I: 'static>(accounts: &[&T::AccountId]) {
+	for who in accounts {
+		Erc::<T, I>::_delegate(who, (*who).clone());
+	}
+}
+
+/// Account holding `role`. Privileged calls are most expensive when made by a role holder, since
+/// unlike `ForceOrigin` it has to be looked up in storage
+fn role_holder<T: Config<I>, I: 'static>(role: Role) -> T::AccountId {
+	let who: T::AccountId = account("holder", 0, SEED);
+	Roles::<T, I>::insert(role, &who, ());
+
+	who
+}
+
+/// Gives `who` enough native currency to reserve allowance and asset deposits
+fn fund_deposit<T: Config<I>, I: 'static>(who: &T::AccountId) {
+	let amount = T::Currency::minimum_balance().saturating_add(
+		T::AllowanceDeposit::get()
+			.saturating_add(T::AssetDeposit::get())
+			.saturating_mul(10u32.into()),
+	);
+
+	T::Currency::make_free_balance_be(who, amount);
+}
+
+/// Creates a token owned by `owner` with `2000` of it issued to the owner
+fn create_asset<T: Config<I>, I: 'static>(owner: &T::AccountId) -> Result<AssetId, BenchmarkError> {
+	let asset = Erc::<T, I>::next_asset_id();
+
+	fund_deposit::<T, I>(owner);
+	Erc::<T, I>::create(
+		RawOrigin::Signed(owner.clone()).into(),
+		b"Token".to_vec(),
+		b"TKN".to_vec(),
+		18,
+		T::Balance::max_value(),
+	)?;
+	Erc::<T, I>::_issue_asset(asset, owner, T::Balance::from(2000u32))?;
+
+	Ok(asset)
+}
+
+/// Gives `who` `count` schedules that lock `MinBalance` each until block `duration`
+fn add_vesting<T: Config<I>, I: 'static>(who: &T::AccountId, count: u32, duration: u32) {
+	let schedule = VestingSchedule {
+		locked: T::MinBalance::get().max(T::Balance::from(1u32)),
+		start: Zero::zero(),
+		cliff: Zero::zero(),
+		duration: BlockNumberFor::<T>::from(duration),
+	};
+
+	Vesting::<T, I>::insert(who, BoundedVec::truncate_from(vec![schedule; count as usize]));
+}
+
 #[instance_benchmarks]
 mod benchmarks {
 	use super::*;
-	use sp_runtime::{
-		traits::{Bounded, StaticLookup},
-		Saturating,
-	};
+	use sp_runtime::{traits::StaticLookup, Saturating};
 
 	#[benchmark]
 	fn transfer() {
@@ -31,6 +97,9 @@ mod benchmarks {
 		let recipient_lookup = T::Lookup::unlookup(recipient.clone());
 
 		//Call
+		delegate_votes::<T, I>(&[&caller, &recipient]);
+		take_snapshot::<T, I>();
+
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller.clone()), recipient_lookup, transfer_amount);
 
@@ -39,8 +108,78 @@ mod benchmarks {
 		assert_eq!(Erc::<T, I>::balance_of(recipient), transfer_amount);
 	}
 
+	#[benchmark]
+	fn transfer_many(n: Linear<1, { T::MaxBatchTransfers::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let original_balance = Erc::<T, I>::balance_of(&caller);
+
+		// Every recipient is a new account and receives the least amount it can hold
+		let transfer_amount = T::MinBalance::get().max(T::Balance::from(1u32));
+		let recipients: Vec<_> = (0..n)
+			.map(|i| (T::Lookup::unlookup(account("recipient", i, SEED)), transfer_amount))
+			.collect();
+
+		delegate_votes::<T, I>(&[&caller]);
+		for i in 0..n {
+			delegate_votes::<T, I>(&[&account("recipient", i, SEED)]);
+		}
+		take_snapshot::<T, I>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), recipients);
+
+		assert_eq!(
+			Erc::<T, I>::balance_of(caller),
+			original_balance.saturating_sub(transfer_amount.saturating_mul(T::Balance::from(n)))
+		);
+	}
+
+	#[benchmark]
+	fn transfer_keep_alive() {
+		let caller: T::AccountId = whitelisted_caller();
+		let original_balance = Erc::<T, I>::balance_of(&caller);
+
+		let transfer_amount = T::Balance::from(500u32);
+
+		let recipient: T::AccountId = account("recipient", 1, SEED);
+		let recipient_lookup = T::Lookup::unlookup(recipient.clone());
+
+		delegate_votes::<T, I>(&[&caller, &recipient]);
+		take_snapshot::<T, I>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), recipient_lookup, transfer_amount);
+
+		assert_eq!(
+			Erc::<T, I>::balance_of(caller),
+			original_balance.saturating_sub(transfer_amount)
+		);
+		assert_eq!(Erc::<T, I>::balance_of(recipient), transfer_amount);
+	}
+
+	#[benchmark]
+	fn transfer_all() {
+		let caller: T::AccountId = whitelisted_caller();
+		let original_balance = Erc::<T, I>::balance_of(&caller);
+
+		let recipient: T::AccountId = account("recipient", 1, SEED);
+		let recipient_lookup = T::Lookup::unlookup(recipient.clone());
+
+		delegate_votes::<T, I>(&[&caller, &recipient]);
+		take_snapshot::<T, I>();
+
+		// Reaping caller's account is the worst case
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), recipient_lookup, false);
+
+		assert!(!Balances::<T, I>::contains_key(&caller));
+		assert_eq!(Erc::<T, I>::balance_of(recipient), original_balance);
+	}
+
 	#[benchmark]
 	fn issue() {
+		let original_supply = Erc::<T, I>::total_supply();
+
 		// How much to issue
 		let issue_amount = T::Balance::from(500u32);
 
@@ -48,19 +187,24 @@ mod benchmarks {
 		let recipient: T::AccountId = account("recipient", 1, SEED);
 		let recipient_lookup = T::Lookup::unlookup(recipient.clone());
 
+		let holder = role_holder::<T, I>(Role::Minter);
+
+		// Rate limit has to be checked and the window updated
+		IssuanceLimit::<T, I>::put((issue_amount, BlockNumberFor::<T>::from(10u32)));
+
+		delegate_votes::<T, I>(&[&recipient]);
+		take_snapshot::<T, I>();
+
 		#[extrinsic_call]
-		_(RawOrigin::Root, recipient_lookup, issue_amount);
+		_(RawOrigin::Signed(holder), recipient_lookup, issue_amount);
 
 		// Verify it
-		assert_eq!(
-			Erc::<T, I>::total_supply(),
-			<T as Config<I>>::Balance::max_value() - issue_amount
-		);
+		assert_eq!(Erc::<T, I>::total_supply(), original_supply.saturating_add(issue_amount));
 		assert_eq!(Erc::<T, I>::balance_of(recipient), issue_amount);
 	}
 
 	#[benchmark]
-	fn transfer_from() {
+	fn transfer_from() -> Result<(), BenchmarkError> {
 		// Account that allows transfer of funds
 		let owner: T::AccountId = whitelisted_caller();
 		let owner_lookup = T::Lookup::unlookup(owner.clone());
@@ -76,7 +220,11 @@ mod benchmarks {
 		let recipient: T::AccountId = account("recipient", 2, SEED);
 		let recipient_lookup = T::Lookup::unlookup(recipient.clone());
 
-		Erc::<T, I>::update_approve(&owner, &allowed, allowance_amount);
+		fund_deposit::<T, I>(&owner);
+		Erc::<T, I>::set_allowance(&owner, &allowed, allowance_amount)?;
+
+		delegate_votes::<T, I>(&[&owner, &recipient]);
+		take_snapshot::<T, I>();
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(allowed.clone()), owner_lookup, recipient_lookup, allowance_amount);
@@ -90,10 +238,125 @@ mod benchmarks {
 		);
 		// Check that recipient received funds
 		assert_eq!(Erc::<T, I>::balance_of(recipient), allowance_amount);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn approve() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+
+		let allowance_amount = T::Balance::from(500u32);
+
+		let recipient: T::AccountId = account("recipient", 2, SEED);
+		let recipient_lookup = T::Lookup::unlookup(recipient.clone());
+
+		// Expiring allowance is the worst case since it's one more storage write
+		let expiry = frame_system::Pallet::<T>::block_number() + 100u32.into();
+
+		fund_deposit::<T, I>(&owner);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), recipient_lookup, allowance_amount, Some(expiry));
+
+		assert_eq!(Erc::<T, I>::allowance_of(&owner, &recipient), allowance_amount);
+		assert_eq!(Erc::<T, I>::allowance_expiry(owner, recipient), Some(expiry));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn permit() {
+		let caller: T::AccountId = whitelisted_caller();
+
+		let (signer, owner) = T::BenchmarkHelper::signer();
+		let spender: T::AccountId = account("spender", 1, SEED);
+
+		let value = T::Balance::from(1000u32);
+		let deadline = BlockNumberFor::<T>::from(1000u32);
+
+		fund_deposit::<T, I>(&owner);
+
+		let payload = Erc::<T, I>::permit_payload(&owner, &spender, value, deadline);
+		let signature = T::BenchmarkHelper::sign(&signer, &payload);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			T::Lookup::unlookup(owner.clone()),
+			T::Lookup::unlookup(spender.clone()),
+			value,
+			deadline,
+			signature,
+		);
+
+		assert_eq!(Erc::<T, I>::allowance_of(&owner, &spender), value);
+	}
+
+	#[benchmark]
+	fn transfer_with_authorization() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+
+		let (signer, from) = T::BenchmarkHelper::signer();
+		let to: T::AccountId = account("recipient", 1, SEED);
+
+		let value = T::Balance::from(500u32);
+		let valid_before = BlockNumberFor::<T>::from(1000u32);
+		let nonce = [1; 32];
+
+		let balance = T::Balance::from(1000u32);
+		Erc::<T, I>::_transfer(&caller, &from, balance, Preservation::Expendable)?;
+
+		let payload = Erc::<T, I>::transfer_authorization_payload(
+			&from,
+			&to,
+			value,
+			Zero::zero(),
+			valid_before,
+			&nonce,
+		);
+		let signature = T::BenchmarkHelper::sign(&signer, &payload);
+
+		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(1u32));
+		delegate_votes::<T, I>(&[&from, &to]);
+		take_snapshot::<T, I>();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			T::Lookup::unlookup(from.clone()),
+			T::Lookup::unlookup(to.clone()),
+			value,
+			Zero::zero(),
+			valid_before,
+			nonce,
+			signature,
+		);
+
+		assert_eq!(Erc::<T, I>::balance_of(to), value);
+		assert!(Erc::<T, I>::is_authorization_used(&from, &nonce));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_authorization() {
+		let caller: T::AccountId = whitelisted_caller();
+
+		let (signer, authorizer) = T::BenchmarkHelper::signer();
+		let nonce = [1; 32];
+
+		let payload = Erc::<T, I>::cancel_authorization_payload(&authorizer, &nonce);
+		let signature = T::BenchmarkHelper::sign(&signer, &payload);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), T::Lookup::unlookup(authorizer.clone()), nonce, signature);
+
+		assert!(Erc::<T, I>::is_authorization_used(&authorizer, &nonce));
 	}
 
 	#[benchmark]
-	fn approve() {
+	fn increase_allowance() -> Result<(), BenchmarkError> {
 		let owner: T::AccountId = whitelisted_caller();
 
 		let allowance_amount = T::Balance::from(500u32);
@@ -101,10 +364,519 @@ mod benchmarks {
 		let recipient: T::AccountId = account("recipient", 2, SEED);
 		let recipient_lookup = T::Lookup::unlookup(recipient.clone());
 
+		fund_deposit::<T, I>(&owner);
+		Erc::<T, I>::set_allowance(&owner, &recipient, allowance_amount)?;
+
 		#[extrinsic_call]
 		_(RawOrigin::Signed(owner.clone()), recipient_lookup, allowance_amount);
 
-		assert_eq!(Erc::<T, I>::allowance_of(owner, recipient), allowance_amount);
+		assert_eq!(
+			Erc::<T, I>::allowance_of(owner, recipient),
+			allowance_amount.saturating_add(allowance_amount)
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn decrease_allowance() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+
+		let allowance_amount = T::Balance::from(500u32);
+
+		let recipient: T::AccountId = account("recipient", 2, SEED);
+		let recipient_lookup = T::Lookup::unlookup(recipient.clone());
+
+		fund_deposit::<T, I>(&owner);
+		Erc::<T, I>::set_allowance(&owner, &recipient, allowance_amount)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), recipient_lookup, allowance_amount);
+
+		assert_eq!(Erc::<T, I>::allowance_of(owner, recipient), T::Balance::zero());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn burn() {
+		let caller: T::AccountId = whitelisted_caller();
+		let original_balance = Erc::<T, I>::balance_of(&caller);
+		let original_supply = Erc::<T, I>::total_supply();
+
+		let burn_amount = T::Balance::from(500u32);
+
+		delegate_votes::<T, I>(&[&caller]);
+		take_snapshot::<T, I>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), burn_amount);
+
+		assert_eq!(Erc::<T, I>::balance_of(caller), original_balance.saturating_sub(burn_amount));
+		assert_eq!(Erc::<T, I>::total_supply(), original_supply.saturating_sub(burn_amount));
+	}
+
+	#[benchmark]
+	fn burn_from() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let owner_lookup = T::Lookup::unlookup(owner.clone());
+		let original_owner_balance = Erc::<T, I>::balance_of(&owner);
+
+		let allowance_amount = T::Balance::from(500u32);
+
+		let allowed: T::AccountId = account("allowed", 1, SEED);
+
+		fund_deposit::<T, I>(&owner);
+		Erc::<T, I>::set_allowance(&owner, &allowed, allowance_amount)?;
+
+		delegate_votes::<T, I>(&[&owner]);
+		take_snapshot::<T, I>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(allowed.clone()), owner_lookup, allowance_amount);
+
+		assert_eq!(Erc::<T, I>::allowance_of(&owner, allowed), T::Balance::zero());
+		assert_eq!(
+			Erc::<T, I>::balance_of(owner),
+			original_owner_balance.saturating_sub(allowance_amount)
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn force_burn() {
+		let owner: T::AccountId = whitelisted_caller();
+		let owner_lookup = T::Lookup::unlookup(owner.clone());
+		let original_balance = Erc::<T, I>::balance_of(&owner);
+
+		let burn_amount = T::Balance::from(500u32);
+
+		let holder = role_holder::<T, I>(Role::Burner);
+
+		delegate_votes::<T, I>(&[&owner]);
+		take_snapshot::<T, I>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(holder), owner_lookup, burn_amount);
+
+		assert_eq!(Erc::<T, I>::balance_of(owner), original_balance.saturating_sub(burn_amount));
+	}
+
+	#[benchmark]
+	fn set_supply_cap() {
+		let cap = Erc::<T, I>::total_supply().saturating_add(T::Balance::from(500u32));
+
+		let holder = role_holder::<T, I>(Role::Admin);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(holder), cap);
+
+		assert_eq!(Erc::<T, I>::supply_cap(), cap);
+	}
+
+	#[benchmark]
+	fn set_metadata() {
+		// Worst case is when every field is as long as possible
+		let name = vec![b'n'; T::MaxNameLength::get() as usize];
+		let symbol = vec![b's'; T::MaxSymbolLength::get() as usize];
+		let uri = vec![b'u'; T::MaxUriLength::get() as usize];
+		let description = vec![b'd'; T::MaxDescriptionLength::get() as usize];
+
+		let holder = role_holder::<T, I>(Role::Admin);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(holder),
+			name.clone(),
+			symbol.clone(),
+			18,
+			Some(uri),
+			Some(description),
+		);
+
+		assert_eq!(Name::<T, I>::get().into_inner(), name);
+		assert_eq!(Symbol::<T, I>::get().into_inner(), symbol);
+		assert_eq!(Erc::<T, I>::decimals(), 18);
+	}
+
+	#[benchmark]
+	fn clear_metadata() {
+		let holder = role_holder::<T, I>(Role::Admin);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(holder));
+
+		assert!(Name::<T, I>::get().is_empty());
+		assert!(Uri::<T, I>::get().is_none());
+		assert_eq!(Erc::<T, I>::decimals(), 0);
+	}
+
+	#[benchmark]
+	fn pause() {
+		let holder = role_holder::<T, I>(Role::Pauser);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(holder));
+
+		assert!(Erc::<T, I>::is_paused());
+	}
+
+	#[benchmark]
+	fn unpause() {
+		Paused::<T, I>::put(true);
+
+		let holder = role_holder::<T, I>(Role::Pauser);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(holder));
+
+		assert!(!Erc::<T, I>::is_paused());
+	}
+
+	#[benchmark]
+	fn freeze() {
+		let who: T::AccountId = account("frozen", 1, SEED);
+		let who_lookup = T::Lookup::unlookup(who.clone());
+
+		let holder = role_holder::<T, I>(Role::Admin);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(holder), who_lookup);
+
+		assert!(Erc::<T, I>::is_frozen(&who));
+	}
+
+	#[benchmark]
+	fn thaw() {
+		let who: T::AccountId = account("frozen", 1, SEED);
+		let who_lookup = T::Lookup::unlookup(who.clone());
+
+		Frozen::<T, I>::insert(&who, ());
+
+		let holder = role_holder::<T, I>(Role::Admin);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(holder), who_lookup);
+
+		assert!(!Erc::<T, I>::is_frozen(&who));
+	}
+
+	#[benchmark]
+	fn snapshot() {
+		let original_id = Erc::<T, I>::current_snapshot_id();
+
+		let holder = role_holder::<T, I>(Role::Admin);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(holder));
+
+		assert_eq!(Erc::<T, I>::current_snapshot_id(), original_id + 1);
+		assert_eq!(
+			Erc::<T, I>::total_supply_at(original_id + 1),
+			Some(Erc::<T, I>::total_supply())
+		);
+	}
+
+	#[benchmark]
+	fn grant_role() {
+		let holder = role_holder::<T, I>(Role::Admin);
+
+		let who: T::AccountId = account("minter", 1, SEED);
+		let who_lookup = T::Lookup::unlookup(who.clone());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(holder), Role::Minter, who_lookup);
+
+		assert!(Erc::<T, I>::has_role(Role::Minter, &who));
+	}
+
+	#[benchmark]
+	fn revoke_role() {
+		let holder = role_holder::<T, I>(Role::Admin);
+
+		let who: T::AccountId = account("minter", 1, SEED);
+		let who_lookup = T::Lookup::unlookup(who.clone());
+
+		Roles::<T, I>::insert(Role::Minter, &who, ());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(holder), Role::Minter, who_lookup);
+
+		assert!(!Erc::<T, I>::has_role(Role::Minter, &who));
+	}
+
+	#[benchmark]
+	fn set_issuance_limit() {
+		let limit = Some((T::Balance::from(1000u32), BlockNumberFor::<T>::from(100u32)));
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, limit);
+
+		assert_eq!(Erc::<T, I>::issuance_limit(), limit);
+	}
+
+	#[benchmark]
+	fn create() {
+		let caller: T::AccountId = whitelisted_caller();
+
+		let name = vec![b'n'; T::MaxNameLength::get() as usize];
+		let symbol = vec![b's'; T::MaxSymbolLength::get() as usize];
+		let cap = T::Balance::from(1_000_000u32);
+
+		let asset = Erc::<T, I>::next_asset_id();
+
+		fund_deposit::<T, I>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), name, symbol, 18, cap);
+
+		assert_eq!(Erc::<T, I>::asset_details(asset).map(|details| details.owner), Ok(caller));
+	}
+
+	#[benchmark]
+	fn transfer_asset() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let asset = create_asset::<T, I>(&caller)?;
+
+		let transfer_amount = T::Balance::from(500u32);
+
+		let recipient: T::AccountId = account("recipient", 1, SEED);
+		let recipient_lookup = T::Lookup::unlookup(recipient.clone());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), asset, recipient_lookup, transfer_amount);
+
+		assert_eq!(Erc::<T, I>::asset_balance_of(asset, caller), T::Balance::from(1500u32));
+		assert_eq!(Erc::<T, I>::asset_balance_of(asset, recipient), transfer_amount);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn approve_asset() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let asset = create_asset::<T, I>(&owner)?;
+
+		let allowance_amount = T::Balance::from(500u32);
+
+		let spender: T::AccountId = account("spender", 1, SEED);
+		let spender_lookup = T::Lookup::unlookup(spender.clone());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), asset, spender_lookup, allowance_amount);
+
+		assert_eq!(Erc::<T, I>::asset_allowance_of(asset, &owner, &spender), allowance_amount);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn transfer_asset_from() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let owner_lookup = T::Lookup::unlookup(owner.clone());
+		let asset = create_asset::<T, I>(&owner)?;
+
+		let allowance_amount = T::Balance::from(500u32);
+
+		let allowed: T::AccountId = account("allowed", 1, SEED);
+
+		let recipient: T::AccountId = account("recipient", 2, SEED);
+		let recipient_lookup = T::Lookup::unlookup(recipient.clone());
+
+		Erc::<T, I>::set_asset_allowance(asset, &owner, &allowed, allowance_amount)?;
+
+		// Spending the whole allowance releases its deposit, which is the worst case
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(allowed.clone()),
+			asset,
+			owner_lookup,
+			recipient_lookup,
+			allowance_amount,
+		);
+
+		assert_eq!(Erc::<T, I>::asset_allowance_of(asset, &owner, &allowed), T::Balance::zero());
+		assert_eq!(Erc::<T, I>::asset_balance_of(asset, recipient), allowance_amount);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn mint_asset() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let asset = create_asset::<T, I>(&owner)?;
+
+		let issue_amount = T::Balance::from(500u32);
+
+		let recipient: T::AccountId = account("recipient", 1, SEED);
+		let recipient_lookup = T::Lookup::unlookup(recipient.clone());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner), asset, recipient_lookup, issue_amount);
+
+		assert_eq!(Erc::<T, I>::asset_balance_of(asset, recipient), issue_amount);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn burn_asset() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let asset = create_asset::<T, I>(&caller)?;
+
+		let burn_amount = T::Balance::from(500u32);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), asset, burn_amount);
+
+		assert_eq!(Erc::<T, I>::asset_balance_of(asset, caller), T::Balance::from(1500u32));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_asset_supply_cap() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let asset = create_asset::<T, I>(&owner)?;
+
+		let cap = T::Balance::from(1_000_000u32);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner), asset, cap);
+
+		assert_eq!(Erc::<T, I>::asset_details(asset).map(|details| details.cap), Ok(cap));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_asset_metadata() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let asset = create_asset::<T, I>(&owner)?;
+
+		let name = vec![b'n'; T::MaxNameLength::get() as usize];
+		let symbol = vec![b's'; T::MaxSymbolLength::get() as usize];
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner), asset, name.clone(), symbol, 6);
+
+		assert_eq!(
+			AssetMetadata::<T, I>::get(asset).map(|(name, ..)| name.into_inner()),
+			Some(name)
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn transfer_asset_ownership() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let asset = create_asset::<T, I>(&owner)?;
+
+		let new_owner: T::AccountId = account("owner", 1, SEED);
+		let new_owner_lookup = T::Lookup::unlookup(new_owner.clone());
+
+		fund_deposit::<T, I>(&new_owner);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner), asset, new_owner_lookup);
+
+		assert_eq!(Erc::<T, I>::asset_details(asset).map(|details| details.owner), Ok(new_owner));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn destroy_asset() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let asset = create_asset::<T, I>(&owner)?;
+
+		// Only token that has nothing left in circulation can be destroyed
+		Erc::<T, I>::_burn_asset(asset, &owner, T::Balance::from(2000u32))?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner), asset);
+
+		assert!(Erc::<T, I>::asset(asset).is_none());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn vested_transfer() {
+		let caller: T::AccountId = whitelisted_caller();
+
+		let recipient: T::AccountId = account("recipient", 1, SEED);
+		let recipient_lookup = T::Lookup::unlookup(recipient.clone());
+
+		// New schedule takes the last free slot and none of the existing ones can be pruned
+		let max = T::MaxVestingSchedules::get();
+		add_vesting::<T, I>(&recipient, max.saturating_sub(1), 1000);
+
+		let schedule = VestingSchedule {
+			locked: T::MinVestedTransfer::get()
+				.max(T::MinBalance::get())
+				.max(T::Balance::from(1u32)),
+			start: Zero::zero(),
+			cliff: Zero::zero(),
+			duration: BlockNumberFor::<T>::from(1000u32),
+		};
+
+		delegate_votes::<T, I>(&[&caller, &recipient]);
+		take_snapshot::<T, I>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), recipient_lookup, schedule);
+
+		assert_eq!(Erc::<T, I>::vesting(&recipient).len() as u32, max);
+	}
+
+	#[benchmark]
+	fn vest() {
+		let caller: T::AccountId = whitelisted_caller();
+
+		// Every schedule is finished and has to be removed
+		add_vesting::<T, I>(&caller, T::MaxVestingSchedules::get(), 1);
+		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(10u32));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert!(Erc::<T, I>::vesting(&caller).is_empty());
+	}
+
+	#[benchmark]
+	fn force_remove_schedule() {
+		let who: T::AccountId = account("vested", 1, SEED);
+		let who_lookup = T::Lookup::unlookup(who.clone());
+
+		let max = T::MaxVestingSchedules::get();
+		add_vesting::<T, I>(&who, max, 1000);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, who_lookup, 0);
+
+		assert_eq!(Erc::<T, I>::vesting(&who).len() as u32, max.saturating_sub(1));
+	}
+
+	#[benchmark]
+	fn delegate() {
+		let caller: T::AccountId = whitelisted_caller();
+
+		// Votes are moved away from the previous delegate, so both of them get a checkpoint
+		let previous: T::AccountId = account("delegate", 1, SEED);
+		let delegatee: T::AccountId = account("delegate", 2, SEED);
+		let delegatee_lookup = T::Lookup::unlookup(delegatee.clone());
+
+		Erc::<T, I>::_delegate(&caller, previous.clone());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), delegatee_lookup);
+
+		assert_eq!(Erc::<T, I>::delegates(&caller), Some(delegatee.clone()));
+		assert_eq!(Erc::<T, I>::get_votes(&previous), T::Balance::zero());
+		assert_eq!(Erc::<T, I>::get_votes(&delegatee), Erc::<T, I>::balance_of(&caller));
 	}
 
 	impl_benchmark_test_suite! {
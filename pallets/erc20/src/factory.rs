@@ -0,0 +1,185 @@
+//! Tokens that any signed account can create with [`create()`](Pallet::create()). They live next
+//! to the instance's own token, keyed by [`AssetId`], and follow the same balance rules through
+//! [`Pallet::checked_debit()`], [`Pallet::checked_credit()`] and [`Pallet::checked_issuance()`]
+
+use super::*;
+use frame_support::{
+	ensure,
+	traits::{tokens::Preservation, Get, ReservableCurrency},
+};
+use sp_runtime::{traits::CheckedSub, DispatchError, DispatchResult, Saturating};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Details of `asset`, fails if there's no such token
+	pub fn asset_details(asset: AssetId) -> Result<AssetDetailsOf<T, I>, DispatchError> {
+		Self::asset(asset).ok_or(Error::<T, I>::UnknownAsset.into())
+	}
+
+	/// Details of `asset`, fails unless `who` owns it
+	pub fn ensure_asset_owner(
+		asset: AssetId,
+		who: &T::AccountId,
+	) -> Result<AssetDetailsOf<T, I>, DispatchError> {
+		let details = Self::asset_details(asset)?;
+		ensure!(details.owner == *who, Error::<T, I>::NotAssetOwner);
+
+		Ok(details)
+	}
+
+	/// Allowance given by `owner` to `spender` on `asset`
+	pub fn asset_allowance_of(
+		asset: AssetId,
+		owner: &T::AccountId,
+		spender: &T::AccountId,
+	) -> T::Balance {
+		<AssetAllowances<T, I>>::get((asset, owner, spender))
+			.map(|approval| approval.amount)
+			.unwrap_or_else(Zero::zero)
+	}
+
+	/// Writes balance of `who` in `asset`, removing its entry once balance drops to zero
+	pub fn update_asset_balance(asset: AssetId, who: &T::AccountId, value: T::Balance) {
+		if value.is_zero() {
+			<AssetBalances<T, I>>::remove(asset, who);
+		} else {
+			<AssetBalances<T, I>>::insert(asset, who, value);
+		}
+	}
+
+	/// Same as [`set_allowance()`](Pallet::set_allowance()) for a token created with
+	/// [`create()`](Pallet::create())
+	pub fn set_asset_allowance(
+		asset: AssetId,
+		from: &T::AccountId,
+		who: &T::AccountId,
+		value: T::Balance,
+	) -> DispatchResult {
+		let key = (asset, from, who);
+
+		if value.is_zero() {
+			if let Some(approval) = <AssetAllowances<T, I>>::take(key) {
+				T::Currency::unreserve(from, approval.deposit);
+			}
+
+			return Ok(())
+		}
+
+		let deposit = match <AssetAllowances<T, I>>::get(key) {
+			Some(approval) => approval.deposit,
+			None => {
+				let deposit = T::AllowanceDeposit::get();
+				T::Currency::reserve(from, deposit)?;
+
+				deposit
+			},
+		};
+
+		<AssetAllowances<T, I>>::insert(key, AssetApproval { amount: value, deposit });
+
+		Ok(())
+	}
+
+	/// Same as [`spend_allowance()`](Pallet::spend_allowance()) for a token created with
+	/// [`create()`](Pallet::create())
+	pub fn spend_asset_allowance(
+		asset: AssetId,
+		owner: &T::AccountId,
+		spender: &T::AccountId,
+		value: T::Balance,
+	) -> DispatchResult {
+		let current = Self::asset_allowance_of(asset, owner, spender);
+		ensure!(!current.is_zero(), Error::<T, I>::NoAllowance);
+
+		let remaining = current.checked_sub(&value).ok_or(Error::<T, I>::NotEnoughAllowance)?;
+
+		Self::set_asset_allowance(asset, owner, spender, remaining)?;
+
+		Self::deposit_event(Event::AssetApproval {
+			asset,
+			from: owner.clone(),
+			to: spender.clone(),
+			value: remaining,
+		});
+
+		Ok(())
+	}
+
+	/// Same as [`_transfer()`](Pallet::_transfer()) for a token created with
+	/// [`create()`](Pallet::create())
+	pub fn _transfer_asset(
+		asset: AssetId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: T::Balance,
+		preservation: Preservation,
+	) -> Result<T::Balance, DispatchError> {
+		Self::asset_details(asset)?;
+
+		if amount.is_zero() {
+			return Ok(amount)
+		}
+
+		let (from_balance, amount) =
+			Self::checked_debit(Self::asset_balance_of(asset, from), amount, preservation)?;
+
+		Self::update_asset_balance(asset, from, from_balance);
+
+		let to_balance = Self::checked_credit(Self::asset_balance_of(asset, to), amount)?;
+
+		Self::update_asset_balance(asset, to, to_balance);
+
+		Self::deposit_event(Event::AssetTransfer {
+			asset,
+			from: from.clone(),
+			to: to.clone(),
+			value: amount,
+		});
+
+		Ok(amount)
+	}
+
+	/// Issues `value` tokens of `asset` to `to` within the token's cap
+	pub fn _issue_asset(asset: AssetId, to: &T::AccountId, value: T::Balance) -> DispatchResult {
+		let mut details = Self::asset_details(asset)?;
+
+		if value.is_zero() {
+			return Ok(())
+		}
+
+		details.supply = Self::checked_issuance(details.supply, details.cap, value)?;
+
+		let balance = Self::checked_credit(Self::asset_balance_of(asset, to), value)?;
+
+		Self::update_asset_balance(asset, to, balance);
+		<Assets<T, I>>::insert(asset, details);
+
+		Self::deposit_event(Event::AssetIssuance { asset, to: to.clone(), value });
+
+		Ok(())
+	}
+
+	/// Same as [`_burn()`](Pallet::_burn()) for a token created with
+	/// [`create()`](Pallet::create())
+	pub fn _burn_asset(asset: AssetId, from: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		let mut details = Self::asset_details(asset)?;
+
+		if amount.is_zero() {
+			return Ok(())
+		}
+
+		let (balance, amount) = Self::checked_debit(
+			Self::asset_balance_of(asset, from),
+			amount,
+			Preservation::Expendable,
+		)?;
+
+		Self::update_asset_balance(asset, from, balance);
+
+		details.supply = details.supply.saturating_sub(amount);
+		<Assets<T, I>>::insert(asset, details);
+
+		Self::deposit_event(Event::AssetBurned { asset, from: from.clone(), value: amount });
+
+		Ok(())
+	}
+}
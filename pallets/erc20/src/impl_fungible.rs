@@ -0,0 +1,230 @@
+//! Implementation of [`fungible`] traits, so any instance of this pallet can be plugged into other
+//! pallets as their currency
+
+use super::*;
+use frame_support::traits::tokens::{
+	fungible::{self, Inspect, Mutate, Unbalanced},
+	DepositConsequence, Fortitude, Precision, Preservation, Provenance, WithdrawConsequence,
+};
+use sp_runtime::{
+	traits::{CheckedSub, Zero},
+	ArithmeticError, DispatchError, Saturating, TokenError,
+};
+
+impl<T: Config<I>, I: 'static> fungible::Inspect<T::AccountId> for Pallet<T, I> {
+	type Balance = T::Balance;
+
+	fn total_issuance() -> Self::Balance {
+		<TotalSupply<T, I>>::get()
+	}
+
+	fn minimum_balance() -> Self::Balance {
+		T::MinBalance::get()
+	}
+
+	fn total_balance(who: &T::AccountId) -> Self::Balance {
+		<Balances<T, I>>::get(who)
+	}
+
+	fn balance(who: &T::AccountId) -> Self::Balance {
+		<Balances<T, I>>::get(who)
+	}
+
+	fn reducible_balance(
+		who: &T::AccountId,
+		preservation: Preservation,
+		_force: Fortitude,
+	) -> Self::Balance {
+		if Self::is_paused() || Self::is_frozen(who) {
+			return Zero::zero()
+		}
+
+		let balance = <Balances<T, I>>::get(who);
+		let locked = Self::locked_balance(who);
+
+		// Account holding locked tokens can't be reaped, so it keeps at least `MinBalance` as well
+		match preservation {
+			Preservation::Expendable if locked.is_zero() => balance,
+			_ => balance.saturating_sub(locked.max(T::MinBalance::get())),
+		}
+	}
+
+	fn can_deposit(
+		who: &T::AccountId,
+		amount: Self::Balance,
+		provenance: Provenance,
+	) -> DepositConsequence {
+		if amount.is_zero() {
+			return DepositConsequence::Success
+		}
+
+		if Self::is_paused() || Self::is_frozen(who) {
+			return DepositConsequence::Blocked
+		}
+
+		// Minting must respect the supply cap in the same way `issue` does
+		if matches!(provenance, Provenance::Minted) {
+			match <TotalSupply<T, I>>::get().checked_add(&amount) {
+				Some(supply) if supply <= <SupplyCap<T, I>>::get() => {},
+				_ => return DepositConsequence::Overflow,
+			}
+		}
+
+		match <Balances<T, I>>::get(who).checked_add(&amount) {
+			Some(balance) if balance < T::MinBalance::get() => DepositConsequence::BelowMinimum,
+			Some(_) => DepositConsequence::Success,
+			None => DepositConsequence::Overflow,
+		}
+	}
+
+	fn can_withdraw(
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> WithdrawConsequence<Self::Balance> {
+		if amount.is_zero() {
+			return WithdrawConsequence::Success
+		}
+
+		if Self::is_paused() || Self::is_frozen(who) {
+			return WithdrawConsequence::Frozen
+		}
+
+		if <TotalSupply<T, I>>::get().checked_sub(&amount).is_none() {
+			return WithdrawConsequence::Underflow
+		}
+
+		let balance = <Balances<T, I>>::get(who);
+
+		if balance < amount {
+			return WithdrawConsequence::BalanceLow
+		}
+
+		// Whatever is left below `MinBalance` is going to be lost as dust
+		let rest = balance.saturating_sub(amount);
+
+		// Locked tokens can neither be withdrawn nor swept along as dust
+		let locked = Self::locked_balance(who);
+
+		if !locked.is_zero() && rest < locked.max(T::MinBalance::get()) {
+			return WithdrawConsequence::Frozen
+		}
+
+		if !rest.is_zero() && rest < T::MinBalance::get() {
+			return WithdrawConsequence::ReducedToZero(rest)
+		}
+
+		WithdrawConsequence::Success
+	}
+}
+
+impl<T: Config<I>, I: 'static> fungible::Unbalanced<T::AccountId> for Pallet<T, I> {
+	fn handle_dust(dust: fungible::Dust<T::AccountId, Self>) {
+		<TotalSupply<T, I>>::mutate(|supply| *supply = supply.saturating_sub(dust.0));
+	}
+
+	fn write_balance(
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> Result<Option<Self::Balance>, DispatchError> {
+		// Balance below minimum is reaped and returned as dust to be handled by the caller
+		if !amount.is_zero() && amount < T::MinBalance::get() {
+			Self::update_balance(who, Zero::zero());
+
+			return Ok(Some(amount))
+		}
+
+		Self::update_balance(who, amount);
+
+		Ok(None)
+	}
+
+	fn set_total_issuance(amount: Self::Balance) {
+		<TotalSupply<T, I>>::put(amount);
+	}
+
+	fn increase_balance(
+		who: &T::AccountId,
+		amount: Self::Balance,
+		precision: Precision,
+	) -> Result<Self::Balance, DispatchError> {
+		// `mint_into` and every `Balanced` credit end up here, so pause and freeze are enforced
+		// once for all of them
+		if Self::is_paused() || Self::is_frozen(who) {
+			return Err(TokenError::Blocked.into())
+		}
+
+		let old_balance = <Balances<T, I>>::get(who);
+		let new_balance = match precision {
+			Precision::BestEffort => old_balance.saturating_add(amount),
+			Precision::Exact =>
+				old_balance.checked_add(&amount).ok_or(ArithmeticError::Overflow)?,
+		};
+
+		if new_balance < T::MinBalance::get() {
+			return match precision {
+				Precision::BestEffort => Ok(Zero::zero()),
+				Precision::Exact => Err(TokenError::BelowMinimum.into()),
+			}
+		}
+
+		if new_balance != old_balance {
+			if let Some(dust) = Self::write_balance(who, new_balance)? {
+				Self::handle_dust(fungible::Dust(dust));
+			}
+		}
+
+		Ok(new_balance.saturating_sub(old_balance))
+	}
+}
+
+impl<T: Config<I>, I: 'static> fungible::Mutate<T::AccountId> for Pallet<T, I> {
+	fn mint_into(who: &T::AccountId, amount: Self::Balance) -> Result<Self::Balance, DispatchError> {
+		Self::can_deposit(who, amount, Provenance::Minted).into_result()?;
+		Self::total_issuance().checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
+
+		let actual = Self::increase_balance(who, amount, Precision::Exact)?;
+		Self::set_total_issuance(Self::total_issuance().saturating_add(actual));
+
+		Self::done_mint_into(who, amount);
+
+		Ok(actual)
+	}
+
+	fn transfer(
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: Self::Balance,
+		preservation: Preservation,
+	) -> Result<Self::Balance, DispatchError> {
+		// Reuse pallet's own transfer so all the checks and events stay the same
+		Self::_transfer(source, dest, amount, preservation)
+	}
+
+	fn done_mint_into(who: &T::AccountId, amount: Self::Balance) {
+		T::OnTransfer::on_issue(who, amount);
+
+		Self::deposit_event(Event::Issuance { to: who.clone(), value: amount });
+	}
+
+	fn done_burn_from(who: &T::AccountId, amount: Self::Balance) {
+		T::OnTransfer::on_burn(who, amount);
+
+		Self::deposit_event(Event::Burned { from: who.clone(), value: amount });
+	}
+}
+
+impl<T: Config<I>, I: 'static> fungible::Balanced<T::AccountId> for Pallet<T, I> {
+	type OnDropCredit = fungible::DecreaseIssuance<T::AccountId, Self>;
+	type OnDropDebt = fungible::IncreaseIssuance<T::AccountId, Self>;
+
+	// Imbalances don't tell where tokens went, so a withdrawal is reported as burn and a deposit
+	// as issue. A withdrawal resolved into another account shows up as both
+
+	fn done_withdraw(who: &T::AccountId, amount: Self::Balance) {
+		T::OnTransfer::on_burn(who, amount);
+	}
+
+	fn done_deposit(who: &T::AccountId, amount: Self::Balance) {
+		T::OnTransfer::on_issue(who, amount);
+	}
+}
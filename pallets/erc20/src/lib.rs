@@ -8,14 +8,157 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+mod factory;
+mod impl_fungible;
+pub mod migrations;
+pub mod roles;
+pub mod runtime_api;
+pub mod traits;
+mod vesting;
+mod votes;
 pub mod weights;
 
+pub use roles::{AdminRole, BurnerRole, EnsureErcRole, MinterRole, PauserRole, Role};
+#[cfg(feature = "runtime-benchmarks")]
+pub use traits::BenchmarkHelper;
+pub use traits::{BeforeTransfer, OnTransfer};
 pub use weights::*;
 
-use sp_runtime::traits::{CheckedAdd, StaticLookup, Zero};
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{
+		AtLeast32BitUnsigned, CheckedAdd, IdentifyAccount, Saturating, StaticLookup, Verify, Zero,
+	},
+	Perbill, RuntimeDebug,
+};
 
 const LOG_TARGET: &str = "runtime::erc";
 
+/// Max length of a raw storage key that [`ExpiryCursor`] can hold
+pub const MAX_EXPIRY_CURSOR_LENGTH: u32 = 256;
+
+/// Identifier of a balances snapshot taken with [`Pallet::snapshot()`]. Ids start from `1`
+pub type SnapshotId = u32;
+
+/// Prefix of every payload signed for [`Pallet::permit()`], so it can't be mistaken for anything
+/// else the owner signs
+pub const PERMIT_TAG: &[u8] = b"erc20:permit";
+
+/// Prefix of every payload signed for [`Pallet::transfer_with_authorization()`]
+pub const TRANSFER_AUTHORIZATION_TAG: &[u8] = b"erc20:transfer_with_authorization";
+
+/// Prefix of every payload signed for [`Pallet::cancel_authorization()`]
+pub const CANCEL_AUTHORIZATION_TAG: &[u8] = b"erc20:cancel_authorization";
+
+/// Random value authorizer picks for every transfer authorization, see
+/// [`Pallet::transfer_with_authorization()`]. Unlike permit nonces they don't have to be used in
+/// order, so several authorizations can be pending at once
+pub type AuthorizationNonce = [u8; 32];
+
+/// Identifier of a token created with [`Pallet::create()`]. Ids are assigned sequentially starting
+/// from `0`
+pub type AssetId = u32;
+
+/// Native currency balance type used for allowance and asset deposits
+pub type DepositBalanceOf<T, I = ()> = <<T as Config<I>>::Currency as frame_support::traits::Currency<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+
+/// State of a token created with [`Pallet::create()`]
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct AssetDetails<AccountId, Balance, DepositBalance> {
+	/// Account that manages the token: issues it, sets its cap and metadata
+	pub owner: AccountId,
+	/// Deposit reserved from `owner` for creating the token
+	pub deposit: DepositBalance,
+	/// Amount of tokens in circulation
+	pub supply: Balance,
+	/// Hard cap on `supply`
+	pub cap: Balance,
+}
+
+/// Allowance on a token created with [`Pallet::create()`] along with deposit reserved for it
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct AssetApproval<Balance, DepositBalance> {
+	pub amount: Balance,
+	pub deposit: DepositBalance,
+}
+
+pub type AssetDetailsOf<T, I = ()> = AssetDetails<
+	<T as frame_system::Config>::AccountId,
+	<T as Config<I>>::Balance,
+	DepositBalanceOf<T, I>,
+>;
+
+pub type AssetApprovalOf<T, I = ()> =
+	AssetApproval<<T as Config<I>>::Balance, DepositBalanceOf<T, I>>;
+
+/// Tokens that are locked on an account and unlock linearly over time. Nothing unlocks until
+/// `cliff` blocks pass since `start`, at that point everything accrued so far unlocks at once and
+/// the rest keeps unlocking every block until `start + duration`
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct VestingSchedule<Balance, BlockNumber> {
+	/// Amount locked before anything unlocks
+	pub locked: Balance,
+	/// Block unlocking is counted from
+	pub start: BlockNumber,
+	/// Number of blocks after `start` before anything unlocks
+	pub cliff: BlockNumber,
+	/// Number of blocks after `start` it takes to unlock everything
+	pub duration: BlockNumber,
+}
+
+impl<Balance, BlockNumber> VestingSchedule<Balance, BlockNumber>
+where
+	Balance: AtLeast32BitUnsigned + Copy,
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+	/// Schedule must lock something and its cliff can't be longer than the whole schedule
+	pub fn is_valid(&self) -> bool {
+		!self.locked.is_zero() && !self.duration.is_zero() && self.cliff <= self.duration
+	}
+
+	/// Amount that is still locked at block `now`
+	pub fn locked_at(&self, now: BlockNumber) -> Balance {
+		let elapsed = now.saturating_sub(self.start);
+
+		if elapsed < self.cliff {
+			return self.locked
+		}
+
+		if elapsed >= self.duration {
+			return Zero::zero()
+		}
+
+		let unlocked = Perbill::from_rational(elapsed, self.duration).mul_floor(self.locked);
+
+		self.locked.saturating_sub(unlocked)
+	}
+}
+
+pub type VestingScheduleOf<T, I = ()> =
+	VestingSchedule<<T as Config<I>>::Balance, frame_system::pallet_prelude::BlockNumberFor<T>>;
+
+/// Number of leading items out of `len` that `is_before` holds for, given that it holds for every
+/// item before the first one it doesn't. History kept in storage under sequential indexes is
+/// searched with it in `O(log len)` reads
+pub(crate) fn partition_point(len: u32, is_before: impl Fn(u32) -> bool) -> u32 {
+	let (mut low, mut high) = (0, len);
+
+	while low < high {
+		let mid = low + (high - low) / 2;
+
+		if is_before(mid) {
+			low = mid + 1;
+		} else {
+			high = mid;
+		}
+	}
+
+	low
+}
+
 /// Utility type that defines RawOrigin conversion to reference accounts in transactions
 pub(crate) type AccountIdLookupOf<T> =
 	<<T as frame_system::Config>::Lookup as StaticLookup>::Source;
@@ -25,17 +168,25 @@ pub mod pallet {
 	use super::*;
 	use codec::Codec;
 	use core::{fmt::Debug, ops::Sub};
-	use frame_support::{ensure, pallet_prelude::*, BoundedVec};
+	use frame_support::{
+		ensure,
+		pallet_prelude::*,
+		traits::{tokens::Preservation, BalanceStatus, PalletInfoAccess, ReservableCurrency},
+		BoundedVec,
+	};
 	use frame_system::pallet_prelude::{OriginFor, *};
 	use scale_info::prelude::{string::String, vec::Vec};
 	use sp_runtime::{
-		traits::{AtLeast32BitUnsigned, Bounded, CheckedSub},
-		FixedPointOperand, Saturating,
+		traits::{AtLeast32BitUnsigned, CheckedSub},
+		ArithmeticError, FixedPointOperand, Saturating,
 	};
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
 	// I decided to make pallet instantiable so multiple instances of ERC20 can exist in one network
 	#[pallet::pallet]
-	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	#[pallet::config]
@@ -45,8 +196,9 @@ pub mod pallet {
 
 		type WeightInfo: WeightInfo;
 
-		/// Defines total supply of a particular token. [`issue()`](Pallet::issue()) extrinsic does
-		/// subtraction from it
+		/// Default hard cap on total supply of a particular token. [`issue()`](Pallet::issue())
+		/// extrinsic can't make circulating supply exceed it. Actual cap is kept in [`SupplyCap`]
+		/// storage and can be changed with [`set_supply_cap()`](Pallet::set_supply_cap())
 		#[pallet::constant]
 		type Supply: Get<u32>;
 
@@ -58,8 +210,69 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxSymbolLength: Get<u32>;
 
+		/// Max possible length of a token URI
+		#[pallet::constant]
+		type MaxUriLength: Get<u32>;
+
+		/// Max possible length of a token description
+		#[pallet::constant]
+		type MaxDescriptionLength: Get<u32>;
+
+		/// Minimum balance an account must hold. Balances below it are treated as dust, accounts
+		/// holding them are removed from storage
+		#[pallet::constant]
+		type MinBalance: Get<Self::Balance>;
+
+		/// Native currency allowance deposits are reserved in
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// Deposit reserved from owner for every new allowance entry. It's returned once the
+		/// allowance is fully spent or revoked
+		#[pallet::constant]
+		type AllowanceDeposit: Get<DepositBalanceOf<Self, I>>;
+
+		/// Deposit reserved from whoever creates a token with [`create()`](Pallet::create()). It
+		/// follows the token when ownership is transferred
+		#[pallet::constant]
+		type AssetDeposit: Get<DepositBalanceOf<Self, I>>;
+
+		/// Max number of recipients in a single [`transfer_many()`](Pallet::transfer_many()) call
+		#[pallet::constant]
+		type MaxBatchTransfers: Get<u32>;
+
+		/// Max number of vesting schedules a single account can have at once
+		#[pallet::constant]
+		type MaxVestingSchedules: Get<u32>;
+
+		/// Least amount [`vested_transfer()`](Pallet::vested_transfer()) can lock. Keeps accounts
+		/// from being filled up with tiny schedules up to `MaxVestingSchedules`
+		#[pallet::constant]
+		type MinVestedTransfer: Get<Self::Balance>;
+
+		/// Handlers notified after this instance's token is transferred, issued or burned. Tokens
+		/// created with [`create()`](Pallet::create()) don't trigger it
+		type OnTransfer: OnTransfer<Self::AccountId, Self::Balance>;
+
+		/// Check every transfer of this instance's token has to pass
+		type BeforeTransfer: BeforeTransfer<Self::AccountId, Self::Balance>;
+
+		/// Signature owner gives its consent with in [`permit()`](Pallet::permit())
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+		/// Public key [`Config::OffchainSignature`] is checked against
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// Signs [`permit()`](Pallet::permit()) payloads in benchmarks
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<
+			Self::AccountId,
+			Self::OffchainPublic,
+			Self::OffchainSignature,
+		>;
+
 		/// The origin that's allowed to make privileged calls and, therefore, issue tokens from
-		/// total supply. In real situation this will be either Root or Sudo call
+		/// total supply. In real situation this will be either Root or Sudo call. It passes every
+		/// [`Role`] check, see [`EnsureErcRole`]
 		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
 		/// This associated type is straight copied from [`Balances`](pallet_balances) to not deal
@@ -77,24 +290,53 @@ pub mod pallet {
 			+ FixedPointOperand;
 	}
 
-	/// Token name, encoded as bytes, UTF-8. Whatever utility is querying storage should do custom
-	/// decoding since we can't stora actual strings in Substrate runtime
+	/// Token name as raw UTF-8 bytes without any length prefix, so whatever utility is querying
+	/// storage can decode it straight into a string. See [`Pallet::name()`]
 	#[pallet::storage]
 	pub type Name<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, BoundedVec<u8, T::MaxNameLength>, ValueQuery>;
 
+	/// Token symbol as raw UTF-8 bytes. See [`Pallet::symbol()`]
 	#[pallet::storage]
 	pub type Symbol<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, BoundedVec<u8, T::MaxSymbolLength>, ValueQuery>;
 
+	/// Number of decimals used to display token amounts, e.g. with 2 decimals balance of `505`
+	/// should be displayed as `5.05`
+	#[pallet::storage]
+	#[pallet::getter(fn decimals)]
+	pub type Decimals<T: Config<I>, I: 'static = ()> = StorageValue<_, u8, ValueQuery>;
+
+	/// Optional URI pointing to off-chain token information, UTF-8
+	#[pallet::storage]
+	pub type Uri<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<u8, T::MaxUriLength>, OptionQuery>;
+
+	/// Optional human readable description of a token, UTF-8
+	#[pallet::storage]
+	pub type Description<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<u8, T::MaxDescriptionLength>, OptionQuery>;
+
 	// TODO: Maybe total supply should use associated type that differs from balance one?
 	// It may create some problems of conversion but with proper `AtLeast` bounds it'll give pallet
 	// more robustness and flexibility
+	/// Amount of tokens that are currently in circulation
 	#[pallet::storage]
 	#[pallet::getter(fn total_supply)]
 	pub type TotalSupply<T: Config<I>, I: 'static = ()> = StorageValue<_, T::Balance, ValueQuery>;
 
-	/// A mapping of accounts to corresponding balances
+	#[pallet::type_value]
+	pub fn DefaultSupplyCap<T: Config<I>, I: 'static>() -> T::Balance {
+		T::Supply::get().into()
+	}
+
+	/// Hard cap on [`TotalSupply`]. Defaults to [`Config::Supply`] until it's set explicitly
+	#[pallet::storage]
+	#[pallet::getter(fn supply_cap)]
+	pub type SupplyCap<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, T::Balance, ValueQuery, DefaultSupplyCap<T, I>>;
+
+	/// A mapping of accounts to corresponding balances. Accounts with zero balance have no entry
 	#[pallet::storage]
 	#[pallet::getter(fn balance_of)]
 	pub type Balances<T: Config<I>, I: 'static = ()> =
@@ -120,6 +362,209 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Block at which allowance given by first account to the second one expires. Allowances that
+	/// don't have an entry here never expire
+	#[pallet::storage]
+	#[pallet::getter(fn allowance_expiry)]
+	pub type AllowanceExpiry<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
+	/// Deposit reserved from first account for the allowance it gave to the second one. Allowances
+	/// created before deposits were introduced have no entry here
+	#[pallet::storage]
+	pub type AllowanceDeposits<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		DepositBalanceOf<T, I>,
+		OptionQuery,
+	>;
+
+	/// Raw key of [`AllowanceExpiry`] entry where `on_idle` stopped looking for expired
+	/// allowances. Next block continues from there
+	#[pallet::storage]
+	pub type ExpiryCursor<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<u8, ConstU32<MAX_EXPIRY_CURSOR_LENGTH>>, OptionQuery>;
+
+	/// Whether all token movements of this instance are stopped
+	#[pallet::storage]
+	#[pallet::getter(fn is_paused)]
+	pub type Paused<T: Config<I>, I: 'static = ()> = StorageValue<_, bool, ValueQuery>;
+
+	/// Accounts that can neither send nor receive tokens
+	#[pallet::storage]
+	pub type Frozen<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// Id of the latest snapshot, zero if no snapshot was taken yet
+	#[pallet::storage]
+	#[pallet::getter(fn current_snapshot_id)]
+	pub type CurrentSnapshotId<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, SnapshotId, ValueQuery>;
+
+	/// Balances account had when snapshots were taken, indexed from the oldest one. Record of a
+	/// snapshot is written lazily right before the first balance change after it, so snapshots
+	/// account didn't change since have no record. See [`Pallet::balance_of_at()`]
+	#[pallet::storage]
+	pub type AccountSnapshots<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		u32,
+		(SnapshotId, T::Balance),
+		OptionQuery,
+	>;
+
+	/// Number of records account has in [`AccountSnapshots`]
+	#[pallet::storage]
+	pub type AccountSnapshotCount<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Total supply at the moment of snapshot. See [`Pallet::total_supply_at()`]
+	#[pallet::storage]
+	pub type TotalSupplySnapshots<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, SnapshotId, T::Balance, OptionQuery>;
+
+	/// Max amount [`Pallet::issue()`] can mint within a window of that many blocks. Issuance is
+	/// unlimited while it's not set
+	#[pallet::storage]
+	#[pallet::getter(fn issuance_limit)]
+	pub type IssuanceLimit<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, (T::Balance, BlockNumberFor<T>), OptionQuery>;
+
+	/// Block the current issuance window started at and amount issued since then
+	#[pallet::storage]
+	pub type IssuanceWindow<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, (BlockNumberFor<T>, T::Balance), ValueQuery>;
+
+	/// Roles granted to accounts. See [`Role`]
+	#[pallet::storage]
+	pub type Roles<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Twox64Concat, Role, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// Id the next token created with [`Pallet::create()`] gets
+	#[pallet::storage]
+	#[pallet::getter(fn next_asset_id)]
+	pub type NextAssetId<T: Config<I>, I: 'static = ()> = StorageValue<_, AssetId, ValueQuery>;
+
+	/// Tokens created with [`Pallet::create()`]
+	#[pallet::storage]
+	#[pallet::getter(fn asset)]
+	pub type Assets<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, AssetId, AssetDetailsOf<T, I>, OptionQuery>;
+
+	/// Name, symbol and decimals of a token created with [`Pallet::create()`], text is UTF-8
+	#[pallet::storage]
+	pub type AssetMetadata<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		(BoundedVec<u8, T::MaxNameLength>, BoundedVec<u8, T::MaxSymbolLength>, u8),
+		OptionQuery,
+	>;
+
+	/// Balances of tokens created with [`Pallet::create()`]. Accounts with zero balance have no
+	/// entry
+	#[pallet::storage]
+	#[pallet::getter(fn asset_balance_of)]
+	pub type AssetBalances<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::Balance,
+		ValueQuery,
+	>;
+
+	/// Allowances on tokens created with [`Pallet::create()`]. Mapping is
+	/// `AssetId -> owner -> spender -> allowance`, the same way [`Allowances`] are
+	#[pallet::storage]
+	pub type AssetAllowances<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, AssetId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		AssetApprovalOf<T, I>,
+		OptionQuery,
+	>;
+
+	/// Vesting schedules of an account. Tokens they still lock can't be transferred, see
+	/// [`Pallet::locked_balance()`]
+	#[pallet::storage]
+	#[pallet::getter(fn vesting)]
+	pub type Vesting<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<VestingScheduleOf<T, I>, T::MaxVestingSchedules>,
+		ValueQuery,
+	>;
+
+	/// Number of permits an account has given so far. Payload of the next one has to include it,
+	/// so a signature can't be used twice. See [`Pallet::permit_payload()`]
+	#[pallet::storage]
+	#[pallet::getter(fn permit_nonce)]
+	pub type PermitNonces<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+	/// Nonces of transfer authorizations that were either used or canceled, so neither can be
+	/// submitted again
+	#[pallet::storage]
+	pub type Authorizations<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		AuthorizationNonce,
+		(),
+		OptionQuery,
+	>;
+
+	/// Account the key delegated its voting power to. Tokens of accounts that never delegated don't
+	/// count as votes at all, not even their own. See [`Pallet::delegate()`]
+	#[pallet::storage]
+	#[pallet::getter(fn delegates)]
+	pub type Delegates<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+	/// Voting power of an account, the sum of balances of every account that delegated to it
+	#[pallet::storage]
+	#[pallet::getter(fn get_votes)]
+	pub type Votes<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::Balance, ValueQuery>;
+
+	/// Voting power an account had at the end of every block it changed in, indexed from the
+	/// oldest block. See [`Pallet::get_past_votes()`]
+	#[pallet::storage]
+	pub type VoteCheckpoints<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		u32,
+		(BlockNumberFor<T>, T::Balance),
+		OptionQuery,
+	>;
+
+	/// Number of checkpoints account has in [`VoteCheckpoints`]
+	#[pallet::storage]
+	#[pallet::getter(fn num_checkpoints)]
+	pub type NumCheckpoints<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
 	// Runtime events
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
@@ -127,6 +572,47 @@ pub mod pallet {
 		Transfer { from: T::AccountId, to: T::AccountId, value: T::Balance },
 		Approval { from: T::AccountId, to: T::AccountId, value: T::Balance },
 		Issuance { to: T::AccountId, value: T::Balance },
+		Burned { from: T::AccountId, value: T::Balance },
+		SupplyCapSet { cap: T::Balance },
+		MetadataSet {
+			name: BoundedVec<u8, T::MaxNameLength>,
+			symbol: BoundedVec<u8, T::MaxSymbolLength>,
+			decimals: u8,
+		},
+		MetadataCleared,
+		Paused,
+		Unpaused,
+		Frozen { who: T::AccountId },
+		Thawed { who: T::AccountId },
+		Snapshot { id: SnapshotId },
+		/// Balance of `who` dropped to zero and its entry was removed from storage
+		AccountReaped { who: T::AccountId },
+		AssetCreated { asset: AssetId, owner: T::AccountId, cap: T::Balance },
+		AssetTransfer { asset: AssetId, from: T::AccountId, to: T::AccountId, value: T::Balance },
+		AssetApproval { asset: AssetId, from: T::AccountId, to: T::AccountId, value: T::Balance },
+		AssetIssuance { asset: AssetId, to: T::AccountId, value: T::Balance },
+		AssetBurned { asset: AssetId, from: T::AccountId, value: T::Balance },
+		AssetSupplyCapSet { asset: AssetId, cap: T::Balance },
+		AssetMetadataSet {
+			asset: AssetId,
+			name: BoundedVec<u8, T::MaxNameLength>,
+			symbol: BoundedVec<u8, T::MaxSymbolLength>,
+			decimals: u8,
+		},
+		AssetOwnerChanged { asset: AssetId, owner: T::AccountId },
+		AssetDestroyed { asset: AssetId },
+		IssuanceLimitSet { limit: Option<(T::Balance, BlockNumberFor<T>)> },
+		RoleGranted { role: Role, who: T::AccountId },
+		RoleRevoked { role: Role, who: T::AccountId },
+		VestingScheduleAdded { who: T::AccountId, schedule: VestingScheduleOf<T, I> },
+		VestingScheduleRemoved { who: T::AccountId, index: u32 },
+		/// Finished schedules of `who` were removed, `locked` is what's still locked by the rest
+		VestingUpdated { who: T::AccountId, locked: T::Balance },
+		AuthorizationUsed { authorizer: T::AccountId, nonce: AuthorizationNonce },
+		AuthorizationCanceled { authorizer: T::AccountId, nonce: AuthorizationNonce },
+		/// `delegator` moved its voting power from `from` to `to`
+		DelegateChanged { delegator: T::AccountId, from: Option<T::AccountId>, to: T::AccountId },
+		DelegateVotesChanged { delegate: T::AccountId, previous: T::Balance, new: T::Balance },
 	}
 
 	#[pallet::error]
@@ -137,15 +623,78 @@ pub mod pallet {
 		NotEnoughSupply,
 		NotEnoughFunds,
 		NotEnoughAllowance,
+		/// Allowance can't be increased any further
+		AllowanceOverflow,
+		/// Allowance has expired and can't be spent anymore
+		AllowanceExpired,
+		/// Allowance expiry must be in the future
+		InvalidExpiry,
+		/// Batch contains more transfers than `MaxBatchTransfers` allows
+		TooManyTransfers,
+		/// Resulting balance would be lower than `MinBalance`
+		BelowMinBalance,
+		/// Transfer would leave sender with less than `MinBalance` while it must be kept alive
+		WouldKillAccount,
+		/// Supply cap can't be set lower than amount of tokens already in circulation
+		CapBelowSupply,
+		/// Metadata field is too long or isn't valid UTF-8
+		BadMetadata,
+		/// Token is paused, so its balances and allowances can't be changed
+		Paused,
+		/// Account is frozen and can neither send nor receive tokens
+		AccountFrozen,
+		/// There's no token with such id
+		UnknownAsset,
+		/// Only owner of the token can do this
+		NotAssetOwner,
+		/// Token can't be destroyed while any of it is in circulation
+		AssetInCirculation,
+		/// Issuing that much would exceed [`IssuanceLimit`] of the current window
+		IssuanceRateExceeded,
+		/// Issuance window must be at least one block long
+		ZeroIssuancePeriod,
+		/// Transfer or burn would take tokens that are still locked by vesting
+		BalanceLocked,
+		/// Schedule locks nothing, has zero duration or its cliff is longer than its duration
+		InvalidVestingSchedule,
+		/// Account already has `MaxVestingSchedules` unfinished schedules
+		TooManyVestingSchedules,
+		/// Account has no vesting schedules
+		NotVesting,
+		/// Account has no vesting schedule with such index
+		UnknownVestingSchedule,
+		/// Vested transfer locks less than `MinVestedTransfer`
+		AmountLow,
+		/// Permit deadline has already passed
+		PermitExpired,
+		/// Signature isn't made by owner over the expected payload
+		BadSignature,
+		/// Transfer authorization can't be used before its `valid_after` block is over
+		AuthorizationNotYetValid,
+		/// Transfer authorization can't be used starting from its `valid_before` block
+		AuthorizationExpired,
+		/// Transfer authorization with this nonce was already used or canceled
+		AuthorizationUsed,
 	}
 
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
 		pub balances: Vec<(T::AccountId, T::Balance)>,
 		pub allowances: Vec<(T::AccountId, (T::AccountId, T::Balance))>,
-		pub total_supply: T::Balance,
+		/// Vesting schedules as `(who, locked, start, cliff, duration)`, see [`VestingSchedule`].
+		/// Locked tokens must be given to `who` in `balances`
+		pub vesting: Vec<(
+			T::AccountId,
+			T::Balance,
+			BlockNumberFor<T>,
+			BlockNumberFor<T>,
+			BlockNumberFor<T>,
+		)>,
+		/// Overrides [`Config::Supply`] as a supply cap if set
+		pub supply_cap: Option<T::Balance>,
 		pub name: String,
 		pub symbol: String,
+		pub decimals: u8,
 		// Eh... rust
 		pub _ignore: PhantomData<I>,
 	}
@@ -155,9 +704,11 @@ pub mod pallet {
 			Self {
 				balances: Default::default(),
 				allowances: Default::default(),
-				total_supply: T::Balance::max_value(),
+				vesting: Default::default(),
+				supply_cap: None,
 				name: String::from("DEFAULT"),
 				symbol: String::from("DEF"),
+				decimals: 18,
 				_ignore: Default::default(),
 			}
 		}
@@ -166,18 +717,66 @@ pub mod pallet {
 	#[pallet::genesis_build]
 	impl<T: Config<I>, I: 'static> BuildGenesisConfig for GenesisConfig<T, I> {
 		fn build(&self) {
-			<Name<T, I>>::put(BoundedVec::truncate_from(self.name.encode()));
-			<Symbol<T, I>>::put(BoundedVec::truncate_from(self.symbol.encode()));
+			let name = BoundedVec::<u8, T::MaxNameLength>::try_from(self.name.as_bytes().to_vec())
+				.expect("Token name is longer than `MaxNameLength`");
+			let symbol =
+				BoundedVec::<u8, T::MaxSymbolLength>::try_from(self.symbol.as_bytes().to_vec())
+					.expect("Token symbol is longer than `MaxSymbolLength`");
+
+			<Name<T, I>>::put(name);
+			<Symbol<T, I>>::put(symbol);
+			<Decimals<T, I>>::put(self.decimals);
 
-			<TotalSupply<T, I>>::put(self.total_supply);
+			let cap = self.supply_cap.unwrap_or_else(|| T::Supply::get().into());
+			let mut total_supply = T::Balance::zero();
 
 			for (a, b) in &self.balances {
+				if b.is_zero() {
+					continue
+				}
+
+				assert!(*b >= T::MinBalance::get(), "Genesis balance is below `MinBalance`");
+
+				total_supply = total_supply
+					.checked_add(b)
+					.expect("Genesis balances must not overflow total supply");
+
 				<Balances<T, I>>::insert(a, b);
 			}
 
+			assert!(total_supply <= cap, "Genesis balances exceed supply cap");
+
+			<SupplyCap<T, I>>::put(cap);
+			<TotalSupply<T, I>>::put(total_supply);
+
 			for &(ref a, ref b) in self.allowances.iter() {
 				<Allowances<T, I>>::insert(a, b.0.clone(), b.1);
 			}
+
+			for &(ref who, locked, start, cliff, duration) in self.vesting.iter() {
+				let schedule = VestingSchedule { locked, start, cliff, duration };
+				assert!(schedule.is_valid(), "Genesis vesting schedule is invalid");
+
+				<Vesting<T, I>>::try_append(who, schedule)
+					.expect("Too many genesis vesting schedules for a single account");
+
+				// Schedules of the same account lock on top of each other
+				let total_locked =
+					<Vesting<T, I>>::get(who).iter().fold(T::Balance::zero(), |total, schedule| {
+						total.saturating_add(schedule.locked)
+					});
+				assert!(
+					total_locked <= <Balances<T, I>>::get(who),
+					"Genesis vesting schedules lock more than account holds"
+				);
+			}
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::remove_expired_allowances(now, remaining_weight)
 		}
 	}
 
@@ -197,7 +796,73 @@ pub mod pallet {
 			// Convert AccountId
 			let dest = T::Lookup::lookup(dest)?;
 
-			Self::_transfer(&from, &dest, value)?;
+			// Dust left after transfer is swept to `dest`, use `transfer_keep_alive` to avoid it
+			Self::_transfer(&from, &dest, value, Preservation::Expendable)?;
+
+			Ok(())
+		}
+
+		/// Same as [`transfer()`](Pallet::transfer()), but fails instead of dropping caller's
+		/// balance below `MinBalance`
+		#[pallet::call_index(6)]
+		pub fn transfer_keep_alive(
+			origin: OriginFor<T>,
+			dest: AccountIdLookupOf<T>,
+			#[pallet::compact] value: T::Balance,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			let dest = T::Lookup::lookup(dest)?;
+
+			Self::_transfer(&from, &dest, value, Preservation::Preserve)?;
+
+			Ok(())
+		}
+
+		/// Transfers whole caller's balance to `dest`. With `keep_alive` set `MinBalance` is left
+		/// on caller's account, otherwise the account is reaped
+		#[pallet::call_index(7)]
+		pub fn transfer_all(
+			origin: OriginFor<T>,
+			dest: AccountIdLookupOf<T>,
+			keep_alive: bool,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			let dest = T::Lookup::lookup(dest)?;
+
+			let (value, preservation) = if keep_alive {
+				(Self::balance_of(&from).saturating_sub(T::MinBalance::get()), Preservation::Preserve)
+			} else {
+				(Self::balance_of(&from), Preservation::Expendable)
+			};
+
+			Self::_transfer(&from, &dest, value, preservation)?;
+
+			Ok(())
+		}
+
+		/// Transfers tokens from caller to every recipient in the batch. Either all transfers succeed
+		/// or none of them is applied
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::transfer_many(recipients.len() as u32))]
+		pub fn transfer_many(
+			origin: OriginFor<T>,
+			recipients: Vec<(AccountIdLookupOf<T>, T::Balance)>,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			ensure!(
+				recipients.len() as u32 <= T::MaxBatchTransfers::get(),
+				Error::<T, I>::TooManyTransfers
+			);
+
+			// Dispatchables are transactional, so failure of any transfer reverts the whole batch
+			for (dest, value) in recipients {
+				let dest = T::Lookup::lookup(dest)?;
+
+				Self::_transfer(&from, &dest, value, Preservation::Expendable)?;
+			}
 
 			Ok(())
 		}
@@ -217,117 +882,1184 @@ pub mod pallet {
 			let to = T::Lookup::lookup(to)?;
 
 			// Update allowance
-			<Allowances<T, I>>::mutate(&from, origin, |allowance| {
-				let current = *allowance;
-
-				// Make sure there's allowance for origin. Will fail if there's none
-				ensure!(current > T::Balance::zero(), Error::<T, I>::NoAllowance);
-
-				// Check if allowance is more or equal to the amount to be transferred and more than
-				// zero
-				ensure!(
-					current >= value && current > T::Balance::from(0u32),
-					Error::<T, I>::NotEnoughAllowance
-				);
-
-				*allowance = current - value;
-				Ok::<_, Error<T, I>>(())
-			})?;
+			let amount = Self::debited_with_dust(Self::balance_of(&from), value);
+			Self::spend_allowance(&from, &origin, amount)?;
 
-			Self::_transfer(&from, &to, value)?;
+			Self::_transfer(&from, &to, value, Preservation::Expendable)?;
 
 			Ok(())
 		}
 
+		/// Sets allowance of `who` to exactly `value`. Zero `value` revokes the allowance. If
+		/// `expiry` is set, allowance can't be spent starting from that block
 		#[pallet::call_index(2)]
 		pub fn approve(
 			origin: OriginFor<T>,
 			who: AccountIdLookupOf<T>,
 			value: T::Balance,
+			expiry: Option<BlockNumberFor<T>>,
 		) -> DispatchResult {
-			if value.is_zero() {
-				return Ok(())
-			}
-
 			// Because this method approves transfer of funds on behalf of other account we need to
 			// make sure it's signed
 			let from = ensure_signed(origin)?;
 
 			let who = T::Lookup::lookup(who)?;
 
-			Self::update_approve(&from, &who, value);
-
-			Self::deposit_event(Event::<T, I>::Approval { from, to: who, value });
+			Self::_approve(&from, &who, value, expiry)?;
 
 			Ok(())
 		}
 
-		#[pallet::call_index(9)]
-		pub fn issue(
+		/// Same as [`approve()`](Pallet::approve()) without expiry, but made on behalf of `owner`
+		/// by anyone who has its `signature` over [`Pallet::permit_payload()`]. Deposit is still
+		/// reserved from `owner`
+		#[pallet::call_index(36)]
+		pub fn permit(
 			origin: OriginFor<T>,
-			to: AccountIdLookupOf<T>,
-			#[pallet::compact] value: T::Balance,
+			owner: AccountIdLookupOf<T>,
+			spender: AccountIdLookupOf<T>,
+			value: T::Balance,
+			deadline: BlockNumberFor<T>,
+			signature: T::OffchainSignature,
 		) -> DispatchResult {
-			// Make sure only root can call this extrinsic or call dispatched by pallet_sudo
-			T::ForceOrigin::ensure_origin(origin)?;
-
-			// Don't do anything if value is zero
-			if value.is_zero() {
-				return Ok(())
-			}
-
-			let dest = T::Lookup::lookup(to)?;
+			ensure_signed(origin)?;
 
-			<TotalSupply<T, I>>::mutate(|supply| {
-				*supply = supply.checked_sub(&value).ok_or(Error::NotEnoughSupply)?;
+			let owner = T::Lookup::lookup(owner)?;
+			let spender = T::Lookup::lookup(spender)?;
 
-				Ok::<(), Error<T, I>>(())
-			})?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= deadline,
+				Error::<T, I>::PermitExpired
+			);
 
-			// Try to update balance
-			<Balances<T, I>>::mutate(&dest, |balance| {
-				*balance = balance.checked_add(&value).ok_or(Error::<T, I>::ReceiverOverflow)?;
+			let payload = Self::permit_payload(&owner, &spender, value, deadline);
+			ensure!(signature.verify(&payload[..], &owner), Error::<T, I>::BadSignature);
 
-				Ok::<(), Error<T, I>>(())
-			})?;
+			<PermitNonces<T, I>>::mutate(&owner, |nonce| *nonce = nonce.saturating_add(1));
 
-			Self::deposit_event(Event::Issuance { to: dest, value });
+			Self::_approve(&owner, &spender, value, None)?;
 
 			Ok(())
 		}
-	}
 
-	impl<T: Config<I>, I: 'static> Pallet<T, I> {
-		pub fn update_balance(account: &T::AccountId, value: T::Balance) {
-			<Balances<T, I>>::set(account, value);
-		}
+		/// Transfers `value` tokens from `from` to `to` with `from`'s consent given as `signature`
+		/// over [`Pallet::transfer_authorization_payload()`]. Anyone can submit it after
+		/// `valid_after` and before `valid_before` block, but only once. Fails if it would leave
+		/// dust behind, since sweeping it along would move more than `from` signed for
+		#[pallet::call_index(37)]
+		pub fn transfer_with_authorization(
+			origin: OriginFor<T>,
+			from: AccountIdLookupOf<T>,
+			to: AccountIdLookupOf<T>,
+			value: T::Balance,
+			valid_after: BlockNumberFor<T>,
+			valid_before: BlockNumberFor<T>,
+			nonce: AuthorizationNonce,
+			signature: T::OffchainSignature,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
 
-		pub fn update_approve(from: &T::AccountId, who: &T::AccountId, value: T::Balance) {
-			<Allowances<T, I>>::mutate(from, who, |allowance| {
-				*allowance = allowance.saturating_add(value);
-			});
-		}
+			let from = T::Lookup::lookup(from)?;
+			let to = T::Lookup::lookup(to)?;
 
-		pub fn _transfer(
-			from: &T::AccountId,
-			to: &T::AccountId,
-			amount: T::Balance,
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now > valid_after, Error::<T, I>::AuthorizationNotYetValid);
+			ensure!(now < valid_before, Error::<T, I>::AuthorizationExpired);
+
+			let payload = Self::transfer_authorization_payload(
+				&from,
+				&to,
+				value,
+				valid_after,
+				valid_before,
+				&nonce,
+			);
+			Self::use_authorization(&from, nonce, &payload, &signature)?;
+
+			let moved = Self::_transfer(&from, &to, value, Preservation::Expendable)?;
+			ensure!(moved == value, Error::<T, I>::WouldKillAccount);
+
+			Self::deposit_event(Event::AuthorizationUsed { authorizer: from, nonce });
+
+			Ok(())
+		}
+
+		/// Makes transfer authorization with `nonce` unusable before anyone submits it.
+		/// `authorizer` gives its consent as `signature` over
+		/// [`Pallet::cancel_authorization_payload()`]
+		#[pallet::call_index(38)]
+		pub fn cancel_authorization(
+			origin: OriginFor<T>,
+			authorizer: AccountIdLookupOf<T>,
+			nonce: AuthorizationNonce,
+			signature: T::OffchainSignature,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let authorizer = T::Lookup::lookup(authorizer)?;
+
+			let payload = Self::cancel_authorization_payload(&authorizer, &nonce);
+			Self::use_authorization(&authorizer, nonce, &payload, &signature)?;
+
+			Self::deposit_event(Event::AuthorizationCanceled { authorizer, nonce });
+
+			Ok(())
+		}
+
+		/// Adds `value` to the allowance of `who`. Expiry of the allowance stays the same
+		#[pallet::call_index(3)]
+		pub fn increase_allowance(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+			#[pallet::compact] value: T::Balance,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			let who = T::Lookup::lookup(who)?;
+
+			Self::ensure_not_paused()?;
+
+			let value = Self::allowance_of(&from, &who)
+				.checked_add(&value)
+				.ok_or(Error::<T, I>::AllowanceOverflow)?;
+
+			Self::set_allowance(&from, &who, value)?;
+
+			Self::deposit_event(Event::<T, I>::Approval { from, to: who, value });
+
+			Ok(())
+		}
+
+		/// Subtracts `value` from the allowance of `who`. Fails if allowance is less than `value`
+		#[pallet::call_index(4)]
+		pub fn decrease_allowance(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+			#[pallet::compact] value: T::Balance,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			let who = T::Lookup::lookup(who)?;
+
+			Self::ensure_not_paused()?;
+
+			let value = Self::allowance_of(&from, &who)
+				.checked_sub(&value)
+				.ok_or(Error::<T, I>::NotEnoughAllowance)?;
+
+			Self::set_allowance(&from, &who, value)?;
+
+			Self::deposit_event(Event::<T, I>::Approval { from, to: who, value });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(9)]
+		pub fn issue(
+			origin: OriginFor<T>,
+			to: AccountIdLookupOf<T>,
+			#[pallet::compact] value: T::Balance,
+		) -> DispatchResult {
+			// Make sure only minter, root or call dispatched by pallet_sudo can call this extrinsic
+			EnsureErcRole::<T, MinterRole, I>::ensure_origin(origin)?;
+
+			// Don't do anything if value is zero
+			if value.is_zero() {
+				return Ok(())
+			}
+
+			let dest = T::Lookup::lookup(to)?;
+
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(&dest)?;
+
+			Self::use_issuance_quota(value)?;
+
+			Self::update_account_snapshot(&dest);
+
+			let supply = Self::checked_issuance(Self::total_supply(), Self::supply_cap(), value)?;
+			<TotalSupply<T, I>>::put(supply);
+
+			// Try to update balance
+			let balance = Self::checked_credit(Self::balance_of(&dest), value)?;
+
+			Self::update_balance(&dest, balance);
+
+			T::OnTransfer::on_issue(&dest, value);
+
+			Self::deposit_event(Event::Issuance { to: dest, value });
+
+			Ok(())
+		}
+
+		/// Destroys `value` tokens from caller's balance, removing them from circulation
+		#[pallet::call_index(10)]
+		pub fn burn(origin: OriginFor<T>, #[pallet::compact] value: T::Balance) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			Self::_burn(&from, value)?;
+
+			Ok(())
+		}
+
+		/// Destroys `value` tokens from `from` balance on behalf of the caller, spending caller's
+		/// allowance the same way [`transfer_from()`](Pallet::transfer_from()) does
+		#[pallet::call_index(11)]
+		pub fn burn_from(
+			origin: OriginFor<T>,
+			from: AccountIdLookupOf<T>,
+			#[pallet::compact] value: T::Balance,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+
+			let from = T::Lookup::lookup(from)?;
+
+			let amount = Self::debited_with_dust(Self::balance_of(&from), value);
+			Self::spend_allowance(&from, &origin, amount)?;
+
+			Self::_burn(&from, value)?;
+
+			Ok(())
+		}
+
+		/// Destroys `value` tokens from `from` balance without any allowance. Only burner can do this
+		#[pallet::call_index(29)]
+		pub fn force_burn(
+			origin: OriginFor<T>,
+			from: AccountIdLookupOf<T>,
+			#[pallet::compact] value: T::Balance,
+		) -> DispatchResult {
+			EnsureErcRole::<T, BurnerRole, I>::ensure_origin(origin)?;
+
+			let from = T::Lookup::lookup(from)?;
+
+			Self::_burn(&from, value)?;
+
+			Ok(())
+		}
+
+		/// Raises or lowers the hard cap on total supply. Cap can't go below the amount that is
+		/// already in circulation
+		#[pallet::call_index(12)]
+		pub fn set_supply_cap(
+			origin: OriginFor<T>,
+			#[pallet::compact] cap: T::Balance,
+		) -> DispatchResult {
+			EnsureErcRole::<T, AdminRole, I>::ensure_origin(origin)?;
+
+			ensure!(cap >= Self::total_supply(), Error::<T, I>::CapBelowSupply);
+
+			<SupplyCap<T, I>>::put(cap);
+
+			Self::deposit_event(Event::SupplyCapSet { cap });
+
+			Ok(())
+		}
+
+		/// Replaces token metadata. Every text field must be valid UTF-8 that fits into
+		/// corresponding `Config` bound
+		#[pallet::call_index(13)]
+		pub fn set_metadata(
+			origin: OriginFor<T>,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
+			uri: Option<Vec<u8>>,
+			description: Option<Vec<u8>>,
+		) -> DispatchResult {
+			EnsureErcRole::<T, AdminRole, I>::ensure_origin(origin)?;
+
+			let name = Self::bounded_utf8::<T::MaxNameLength>(name)?;
+			let symbol = Self::bounded_utf8::<T::MaxSymbolLength>(symbol)?;
+			let uri = uri.map(Self::bounded_utf8::<T::MaxUriLength>).transpose()?;
+			let description =
+				description.map(Self::bounded_utf8::<T::MaxDescriptionLength>).transpose()?;
+
+			<Name<T, I>>::put(&name);
+			<Symbol<T, I>>::put(&symbol);
+			<Decimals<T, I>>::put(decimals);
+			<Uri<T, I>>::set(uri);
+			<Description<T, I>>::set(description);
+
+			Self::deposit_event(Event::MetadataSet { name, symbol, decimals });
+
+			Ok(())
+		}
+
+		/// Removes all token metadata
+		#[pallet::call_index(14)]
+		pub fn clear_metadata(origin: OriginFor<T>) -> DispatchResult {
+			EnsureErcRole::<T, AdminRole, I>::ensure_origin(origin)?;
+
+			<Name<T, I>>::kill();
+			<Symbol<T, I>>::kill();
+			<Decimals<T, I>>::kill();
+			<Uri<T, I>>::kill();
+			<Description<T, I>>::kill();
+
+			Self::deposit_event(Event::MetadataCleared);
+
+			Ok(())
+		}
+
+		/// Stops every token movement of this instance until [`unpause()`](Pallet::unpause()) is
+		/// called
+		#[pallet::call_index(15)]
+		pub fn pause(origin: OriginFor<T>) -> DispatchResult {
+			EnsureErcRole::<T, PauserRole, I>::ensure_origin(origin)?;
+
+			<Paused<T, I>>::put(true);
+
+			Self::deposit_event(Event::Paused);
+
+			Ok(())
+		}
+
+		#[pallet::call_index(16)]
+		pub fn unpause(origin: OriginFor<T>) -> DispatchResult {
+			EnsureErcRole::<T, PauserRole, I>::ensure_origin(origin)?;
+
+			<Paused<T, I>>::kill();
+
+			Self::deposit_event(Event::Unpaused);
+
+			Ok(())
+		}
+
+		/// Forbids `who` to send or receive tokens until [`thaw()`](Pallet::thaw()) is called
+		#[pallet::call_index(17)]
+		pub fn freeze(origin: OriginFor<T>, who: AccountIdLookupOf<T>) -> DispatchResult {
+			EnsureErcRole::<T, AdminRole, I>::ensure_origin(origin)?;
+
+			let who = T::Lookup::lookup(who)?;
+
+			<Frozen<T, I>>::insert(&who, ());
+
+			Self::deposit_event(Event::Frozen { who });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(18)]
+		pub fn thaw(origin: OriginFor<T>, who: AccountIdLookupOf<T>) -> DispatchResult {
+			EnsureErcRole::<T, AdminRole, I>::ensure_origin(origin)?;
+
+			let who = T::Lookup::lookup(who)?;
+
+			<Frozen<T, I>>::remove(&who);
+
+			Self::deposit_event(Event::Thawed { who });
+
+			Ok(())
+		}
+
+		/// Records current balances and total supply under a new snapshot id, which is emitted in
+		/// [`Event::Snapshot`]. Only total supply is copied here, balances are written lazily on
+		/// their first change after the snapshot
+		#[pallet::call_index(19)]
+		pub fn snapshot(origin: OriginFor<T>) -> DispatchResult {
+			EnsureErcRole::<T, AdminRole, I>::ensure_origin(origin)?;
+
+			let id = Self::current_snapshot_id().checked_add(1).ok_or(ArithmeticError::Overflow)?;
+
+			<CurrentSnapshotId<T, I>>::put(id);
+			<TotalSupplySnapshots<T, I>>::insert(id, Self::total_supply());
+
+			Self::deposit_event(Event::Snapshot { id });
+
+			Ok(())
+		}
+
+		/// Grants `role` to `who`. Only admin can do this
+		#[pallet::call_index(30)]
+		pub fn grant_role(
+			origin: OriginFor<T>,
+			role: Role,
+			who: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			EnsureErcRole::<T, AdminRole, I>::ensure_origin(origin)?;
+
+			let who = T::Lookup::lookup(who)?;
+
+			<Roles<T, I>>::insert(role, &who, ());
+
+			Self::deposit_event(Event::RoleGranted { role, who });
+
+			Ok(())
+		}
+
+		/// Takes `role` away from `who`. Only admin can do this
+		#[pallet::call_index(31)]
+		pub fn revoke_role(
+			origin: OriginFor<T>,
+			role: Role,
+			who: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			EnsureErcRole::<T, AdminRole, I>::ensure_origin(origin)?;
+
+			let who = T::Lookup::lookup(who)?;
+
+			<Roles<T, I>>::remove(role, &who);
+
+			Self::deposit_event(Event::RoleRevoked { role, who });
+
+			Ok(())
+		}
+
+		/// Limits [`issue()`](Pallet::issue()) to `max` tokens per `period` blocks, `None` lifts the
+		/// limit. Amount already issued in the current window still counts
+		#[pallet::call_index(32)]
+		pub fn set_issuance_limit(
+			origin: OriginFor<T>,
+			limit: Option<(T::Balance, BlockNumberFor<T>)>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			if let Some((_, period)) = limit {
+				ensure!(!period.is_zero(), Error::<T, I>::ZeroIssuancePeriod);
+			}
+
+			<IssuanceLimit<T, I>>::set(limit);
+
+			Self::deposit_event(Event::IssuanceLimitSet { limit });
+
+			Ok(())
+		}
+
+		/// Creates a new token owned by the caller, reserving [`Config::AssetDeposit`] from it.
+		/// Token gets the next [`AssetId`] and has no supply until owner issues it with
+		/// [`mint_asset()`](Pallet::mint_asset())
+		#[pallet::call_index(20)]
+		pub fn create(
+			origin: OriginFor<T>,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
+			#[pallet::compact] cap: T::Balance,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			let name = Self::bounded_utf8::<T::MaxNameLength>(name)?;
+			let symbol = Self::bounded_utf8::<T::MaxSymbolLength>(symbol)?;
+
+			let asset = Self::next_asset_id();
+			<NextAssetId<T, I>>::put(asset.checked_add(1).ok_or(ArithmeticError::Overflow)?);
+
+			let deposit = T::AssetDeposit::get();
+			T::Currency::reserve(&owner, deposit)?;
+
+			<Assets<T, I>>::insert(
+				asset,
+				AssetDetails { owner: owner.clone(), deposit, supply: Zero::zero(), cap },
+			);
+			<AssetMetadata<T, I>>::insert(asset, (name, symbol, decimals));
+
+			Self::deposit_event(Event::AssetCreated { asset, owner, cap });
+
+			Ok(())
+		}
+
+		/// Same as [`transfer()`](Pallet::transfer()) for a token created with
+		/// [`create()`](Pallet::create())
+		#[pallet::call_index(21)]
+		pub fn transfer_asset(
+			origin: OriginFor<T>,
+			asset: AssetId,
+			dest: AccountIdLookupOf<T>,
+			#[pallet::compact] value: T::Balance,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			let dest = T::Lookup::lookup(dest)?;
+
+			Self::_transfer_asset(asset, &from, &dest, value, Preservation::Expendable)?;
+
+			Ok(())
+		}
+
+		/// Sets allowance of `who` on `asset` to exactly `value`. Zero `value` revokes the
+		/// allowance
+		#[pallet::call_index(22)]
+		pub fn approve_asset(
+			origin: OriginFor<T>,
+			asset: AssetId,
+			who: AccountIdLookupOf<T>,
+			value: T::Balance,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			let who = T::Lookup::lookup(who)?;
+
+			// Allowance on a destroyed token can still be revoked to get its deposit back
+			if !value.is_zero() {
+				Self::asset_details(asset)?;
+			}
+
+			Self::set_asset_allowance(asset, &from, &who, value)?;
+
+			Self::deposit_event(Event::AssetApproval { asset, from, to: who, value });
+
+			Ok(())
+		}
+
+		/// Same as [`transfer_from()`](Pallet::transfer_from()) for a token created with
+		/// [`create()`](Pallet::create())
+		#[pallet::call_index(23)]
+		pub fn transfer_asset_from(
+			origin: OriginFor<T>,
+			asset: AssetId,
+			from: AccountIdLookupOf<T>,
+			to: AccountIdLookupOf<T>,
+			value: T::Balance,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+
+			let from = T::Lookup::lookup(from)?;
+			let to = T::Lookup::lookup(to)?;
+
+			let amount = Self::debited_with_dust(Self::asset_balance_of(asset, &from), value);
+			Self::spend_asset_allowance(asset, &from, &origin, amount)?;
+
+			Self::_transfer_asset(asset, &from, &to, value, Preservation::Expendable)?;
+
+			Ok(())
+		}
+
+		/// Issues `value` tokens of `asset` to `to`. Only token owner can do this
+		#[pallet::call_index(24)]
+		pub fn mint_asset(
+			origin: OriginFor<T>,
+			asset: AssetId,
+			to: AccountIdLookupOf<T>,
+			#[pallet::compact] value: T::Balance,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			let to = T::Lookup::lookup(to)?;
+
+			Self::ensure_asset_owner(asset, &owner)?;
+
+			Self::_issue_asset(asset, &to, value)?;
+
+			Ok(())
+		}
+
+		/// Destroys `value` tokens of `asset` from caller's balance
+		#[pallet::call_index(25)]
+		pub fn burn_asset(
+			origin: OriginFor<T>,
+			asset: AssetId,
+			#[pallet::compact] value: T::Balance,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			Self::_burn_asset(asset, &from, value)?;
+
+			Ok(())
+		}
+
+		/// Raises or lowers the hard cap on supply of `asset`. Only token owner can do this
+		#[pallet::call_index(26)]
+		pub fn set_asset_supply_cap(
+			origin: OriginFor<T>,
+			asset: AssetId,
+			#[pallet::compact] cap: T::Balance,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			let mut details = Self::ensure_asset_owner(asset, &owner)?;
+			ensure!(cap >= details.supply, Error::<T, I>::CapBelowSupply);
+
+			details.cap = cap;
+			<Assets<T, I>>::insert(asset, details);
+
+			Self::deposit_event(Event::AssetSupplyCapSet { asset, cap });
+
+			Ok(())
+		}
+
+		/// Replaces name, symbol and decimals of `asset`. Only token owner can do this
+		#[pallet::call_index(27)]
+		pub fn set_asset_metadata(
+			origin: OriginFor<T>,
+			asset: AssetId,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
 		) -> DispatchResult {
-			if amount.is_zero() {
+			let owner = ensure_signed(origin)?;
+
+			Self::ensure_asset_owner(asset, &owner)?;
+
+			let name = Self::bounded_utf8::<T::MaxNameLength>(name)?;
+			let symbol = Self::bounded_utf8::<T::MaxSymbolLength>(symbol)?;
+
+			<AssetMetadata<T, I>>::insert(asset, (&name, &symbol, decimals));
+
+			Self::deposit_event(Event::AssetMetadataSet { asset, name, symbol, decimals });
+
+			Ok(())
+		}
+
+		/// Hands `asset` over to `new_owner`. Creation deposit reserved from the current owner is
+		/// moved to the new one, so it doesn't have to sign anything
+		#[pallet::call_index(28)]
+		pub fn transfer_asset_ownership(
+			origin: OriginFor<T>,
+			asset: AssetId,
+			new_owner: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			let new_owner = T::Lookup::lookup(new_owner)?;
+
+			let mut details = Self::ensure_asset_owner(asset, &owner)?;
+
+			if owner == new_owner {
 				return Ok(())
 			}
 
-			<Balances<T, I>>::mutate(from, |balance| {
-				ensure!(*balance >= amount, Error::NotEnoughFunds);
-				*balance = balance.sub(amount);
-				Ok::<(), Error<T, I>>(())
-			})?;
+			T::Currency::repatriate_reserved(
+				&owner,
+				&new_owner,
+				details.deposit,
+				BalanceStatus::Reserved,
+			)?;
+
+			details.owner = new_owner.clone();
+			<Assets<T, I>>::insert(asset, details);
+
+			Self::deposit_event(Event::AssetOwnerChanged { asset, owner: new_owner });
+
+			Ok(())
+		}
+
+		/// Removes `asset` once none of it is in circulation and gives creation deposit back to its
+		/// owner. Only token owner can do this
+		#[pallet::call_index(40)]
+		pub fn destroy_asset(origin: OriginFor<T>, asset: AssetId) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			let details = Self::ensure_asset_owner(asset, &owner)?;
+			ensure!(details.supply.is_zero(), Error::<T, I>::AssetInCirculation);
+
+			<Assets<T, I>>::remove(asset);
+			<AssetMetadata<T, I>>::remove(asset);
+
+			T::Currency::unreserve(&owner, details.deposit);
+
+			Self::deposit_event(Event::AssetDestroyed { asset });
+
+			Ok(())
+		}
+
+		/// Transfers `schedule.locked` tokens to `dest` and locks them there according to
+		/// `schedule`, which must lock at least `MinVestedTransfer`. Finished schedules of `dest`
+		/// are removed first to make room for the new one
+		#[pallet::call_index(33)]
+		pub fn vested_transfer(
+			origin: OriginFor<T>,
+			dest: AccountIdLookupOf<T>,
+			schedule: VestingScheduleOf<T, I>,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			let dest = T::Lookup::lookup(dest)?;
+
+			ensure!(schedule.locked >= T::MinVestedTransfer::get(), Error::<T, I>::AmountLow);
+
+			Self::add_vesting_schedule(&dest, schedule)?;
+
+			Self::_transfer(&from, &dest, schedule.locked, Preservation::Expendable)?;
+
+			Ok(())
+		}
+
+		/// Removes finished vesting schedules of the caller. Tokens unlock over time on their own,
+		/// this only cleans up storage
+		#[pallet::call_index(34)]
+		pub fn vest(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(<Vesting<T, I>>::contains_key(&who), Error::<T, I>::NotVesting);
+
+			let locked = Self::prune_vesting_schedules(&who);
+
+			Self::deposit_event(Event::VestingUpdated { who, locked });
+
+			Ok(())
+		}
+
+		/// Removes vesting schedule at `index` of `who`, unlocking whatever it still locks
+		#[pallet::call_index(35)]
+		pub fn force_remove_schedule(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+			index: u32,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let who = T::Lookup::lookup(who)?;
+
+			<Vesting<T, I>>::try_mutate_exists(&who, |schedules| {
+				let list = schedules.as_mut().ok_or(Error::<T, I>::NotVesting)?;
+
+				ensure!((index as usize) < list.len(), Error::<T, I>::UnknownVestingSchedule);
+				list.remove(index as usize);
+
+				if list.is_empty() {
+					*schedules = None;
+				}
 
-			<Balances<T, I>>::mutate(to, |balance| {
-				*balance = balance.checked_add(&amount).ok_or(Error::ReceiverOverflow)?;
-				Ok::<(), Error<T, I>>(())
+				Ok::<_, DispatchError>(())
 			})?;
 
+			Self::deposit_event(Event::VestingScheduleRemoved { who, index });
+
+			Ok(())
+		}
+
+		/// Delegates voting power of the caller to `delegatee`, which may be the caller itself.
+		/// Votes follow every later balance change of the caller until it delegates elsewhere
+		#[pallet::call_index(39)]
+		pub fn delegate(origin: OriginFor<T>, delegatee: AccountIdLookupOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let delegatee = T::Lookup::lookup(delegatee)?;
+
+			Self::_delegate(&who, delegatee);
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Writes balance of `account`, removing its entry once balance drops to zero
+		pub fn update_balance(account: &T::AccountId, value: T::Balance) {
+			Self::update_account_snapshot(account);
+			Self::update_delegated_votes(account, value);
+
+			if !value.is_zero() {
+				<Balances<T, I>>::insert(account, value);
+			} else if <Balances<T, I>>::contains_key(account) {
+				<Balances<T, I>>::remove(account);
+
+				Self::deposit_event(Event::AccountReaped { who: account.clone() });
+			}
+		}
+
+		/// Must be called before balance of `who` is changed, copies it into the current snapshot
+		/// unless it's already there
+		pub fn update_account_snapshot(who: &T::AccountId) {
+			let id = Self::current_snapshot_id();
+
+			if id.is_zero() {
+				return
+			}
+
+			let count = <AccountSnapshotCount<T, I>>::get(who);
+			let latest =
+				count.checked_sub(1).and_then(|last| <AccountSnapshots<T, I>>::get(who, last));
+
+			if latest.map_or(false, |(snapshot, _)| snapshot == id) {
+				return
+			}
+
+			<AccountSnapshots<T, I>>::insert(who, count, (id, Self::balance_of(who)));
+			<AccountSnapshotCount<T, I>>::insert(who, count.saturating_add(1));
+		}
+
+		/// Balance `who` had when snapshot `id` was taken, `None` if there's no such snapshot
+		pub fn balance_of_at(who: &T::AccountId, id: SnapshotId) -> Option<T::Balance> {
+			if id.is_zero() || id > Self::current_snapshot_id() {
+				return None
+			}
+
+			// Balance didn't change between snapshot `id` and the earliest recorded one after it,
+			// so that record holds the answer. If there's none, balance didn't change at all
+			let index = partition_point(<AccountSnapshotCount<T, I>>::get(who), |index| {
+				<AccountSnapshots<T, I>>::get(who, index)
+					.map_or(false, |(snapshot, _)| snapshot < id)
+			});
+			let recorded = <AccountSnapshots<T, I>>::get(who, index).map(|(_, balance)| balance);
+
+			Some(recorded.unwrap_or_else(|| Self::balance_of(who)))
+		}
+
+		/// Total supply at the moment snapshot `id` was taken, `None` if there's no such snapshot
+		pub fn total_supply_at(id: SnapshotId) -> Option<T::Balance> {
+			<TotalSupplySnapshots<T, I>>::get(id)
+		}
+
+		/// Current issuance window and amount issued in it. Window that is over is reported as a
+		/// fresh one starting at the current block
+		fn issuance_window(period: BlockNumberFor<T>) -> (BlockNumberFor<T>, T::Balance) {
+			let now = frame_system::Pallet::<T>::block_number();
+			let (start, issued) = <IssuanceWindow<T, I>>::get();
+
+			if now >= start.saturating_add(period) {
+				(now, Zero::zero())
+			} else {
+				(start, issued)
+			}
+		}
+
+		/// How much can still be issued in the current window, `None` if issuance isn't limited
+		pub fn remaining_issuance() -> Option<T::Balance> {
+			let (max, period) = Self::issuance_limit()?;
+			let (_, issued) = Self::issuance_window(period);
+
+			Some(max.saturating_sub(issued))
+		}
+
+		/// Counts `value` against [`IssuanceLimit`], fails if the current window can't fit it
+		pub fn use_issuance_quota(value: T::Balance) -> DispatchResult {
+			let Some((max, period)) = Self::issuance_limit() else { return Ok(()) };
+
+			let (start, issued) = Self::issuance_window(period);
+			let issued = issued
+				.checked_add(&value)
+				.filter(|issued| *issued <= max)
+				.ok_or(Error::<T, I>::IssuanceRateExceeded)?;
+
+			<IssuanceWindow<T, I>>::put((start, issued));
+
+			Ok(())
+		}
+
+		pub fn has_role(role: Role, who: &T::AccountId) -> bool {
+			<Roles<T, I>>::contains_key(role, who)
+		}
+
+		pub fn is_frozen(who: &T::AccountId) -> bool {
+			<Frozen<T, I>>::contains_key(who)
+		}
+
+		pub fn ensure_not_paused() -> DispatchResult {
+			ensure!(!Self::is_paused(), Error::<T, I>::Paused);
+
+			Ok(())
+		}
+
+		pub fn ensure_not_frozen(who: &T::AccountId) -> DispatchResult {
+			ensure!(!Self::is_frozen(who), Error::<T, I>::AccountFrozen);
+
+			Ok(())
+		}
+
+		/// Token name, UTF-8 bytes that can be decoded straight into `String`
+		pub fn name() -> Vec<u8> {
+			<Name<T, I>>::get().into_inner()
+		}
+
+		/// Token symbol, UTF-8 bytes that can be decoded straight into `String`
+		pub fn symbol() -> Vec<u8> {
+			<Symbol<T, I>>::get().into_inner()
+		}
+
+		/// Makes sure `value` is valid UTF-8 that fits into `S` bytes
+		pub fn bounded_utf8<S: Get<u32>>(value: Vec<u8>) -> Result<BoundedVec<u8, S>, DispatchError> {
+			ensure!(core::str::from_utf8(&value).is_ok(), Error::<T, I>::BadMetadata);
+
+			BoundedVec::try_from(value).map_err(|_| Error::<T, I>::BadMetadata.into())
+		}
+
+		/// Sets allowance given by `from` to `who` to exactly `value`. New entry reserves
+		/// [`Config::AllowanceDeposit`] from `from`, zero `value` removes the entry along with its
+		/// expiry and releases the deposit
+		pub fn set_allowance(
+			from: &T::AccountId,
+			who: &T::AccountId,
+			value: T::Balance,
+		) -> DispatchResult {
+			if value.is_zero() {
+				<Allowances<T, I>>::remove(from, who);
+				<AllowanceExpiry<T, I>>::remove(from, who);
+
+				if let Some(deposit) = <AllowanceDeposits<T, I>>::take(from, who) {
+					T::Currency::unreserve(from, deposit);
+				}
+			} else {
+				// Only new entries take up storage, so only they are charged
+				let deposit = T::AllowanceDeposit::get();
+
+				if !<Allowances<T, I>>::contains_key(from, who) && !deposit.is_zero() {
+					T::Currency::reserve(from, deposit)?;
+
+					<AllowanceDeposits<T, I>>::insert(from, who, deposit);
+				}
+
+				<Allowances<T, I>>::insert(from, who, value);
+			}
+
+			Ok(())
+		}
+
+		/// Amount that leaves account holding `balance` when `value` is taken from it and dust is
+		/// swept along. Spenders are charged that much, so they can't move more than they were
+		/// allowed to. If `value` can't be taken at all, it's left for the transfer to fail
+		pub fn debited_with_dust(balance: T::Balance, value: T::Balance) -> T::Balance {
+			Self::checked_debit(balance, value, Preservation::Expendable)
+				.map_or(value, |(_, amount)| amount)
+		}
+
+		/// Decreases allowance given by `owner` to `spender` by `value`. Fails if there's none or it
+		/// is not enough. Emits [`Event::Approval`] with the remaining allowance, as ERC-20 does
+		pub fn spend_allowance(
+			owner: &T::AccountId,
+			spender: &T::AccountId,
+			value: T::Balance,
+		) -> DispatchResult {
+			let current = Self::allowance_of(owner, spender);
+
+			// Make sure there's allowance for origin. Will fail if there's none
+			ensure!(!current.is_zero(), Error::<T, I>::NoAllowance);
+
+			if let Some(expiry) = Self::allowance_expiry(owner, spender) {
+				ensure!(
+					frame_system::Pallet::<T>::block_number() < expiry,
+					Error::<T, I>::AllowanceExpired
+				);
+			}
+
+			// Check if allowance is more or equal to the amount to be transferred
+			let remaining = current.checked_sub(&value).ok_or(Error::<T, I>::NotEnoughAllowance)?;
+
+			Self::set_allowance(owner, spender, remaining)?;
+
+			Self::deposit_event(Event::Approval {
+				from: owner.clone(),
+				to: spender.clone(),
+				value: remaining,
+			});
+
+			Ok(())
+		}
+
+		/// Walks over [`AllowanceExpiry`] starting from [`ExpiryCursor`] and removes allowances
+		/// that have expired by `now`, as long as `remaining_weight` allows. Returns consumed weight
+		pub fn remove_expired_allowances(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let db_weight = T::DbWeight::get();
+			// Cursor is read and written once
+			let mut consumed = db_weight.reads_writes(1, 1);
+			// Every visited entry is read, expired ones are removed from all allowance maps and
+			// their deposit is unreserved
+			let per_entry = db_weight.reads_writes(3, 4);
+
+			if remaining_weight.any_lt(consumed.saturating_add(per_entry)) {
+				return Weight::zero()
+			}
+
+			let mut iter = match <ExpiryCursor<T, I>>::get() {
+				Some(cursor) => <AllowanceExpiry<T, I>>::iter_from(cursor.into_inner()),
+				None => <AllowanceExpiry<T, I>>::iter(),
+			};
+
+			let mut expired = Vec::new();
+			let mut last_key = None;
+
+			while remaining_weight.all_gte(consumed.saturating_add(per_entry)) {
+				let Some((owner, spender, expiry)) = iter.next() else {
+					// Reached the end, next time start from the beginning
+					last_key = None;
+					break
+				};
+
+				consumed.saturating_accrue(per_entry);
+				last_key = Some(<AllowanceExpiry<T, I>>::hashed_key_for(&owner, &spender));
+
+				if expiry <= now {
+					expired.push((owner, spender));
+				}
+			}
+
+			match last_key.and_then(|key| BoundedVec::try_from(key).ok()) {
+				Some(cursor) => <ExpiryCursor<T, I>>::put(cursor),
+				None => <ExpiryCursor<T, I>>::kill(),
+			}
+
+			for (owner, spender) in expired {
+				// Removing an allowance can't fail, only creating one reserves a deposit
+				let _ = Self::set_allowance(&owner, &spender, Zero::zero());
+
+				Self::deposit_event(Event::Approval { from: owner, to: spender, value: Zero::zero() });
+			}
+
+			consumed
+		}
+
+		/// Sets allowance given by `from` to `who` to exactly `value` and emits
+		/// [`Event::Approval`]. If `expiry` is set, allowance can't be spent starting from that
+		/// block
+		pub fn _approve(
+			from: &T::AccountId,
+			who: &T::AccountId,
+			value: T::Balance,
+			expiry: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			Self::ensure_not_paused()?;
+
+			if let Some(expiry) = expiry {
+				ensure!(
+					expiry > frame_system::Pallet::<T>::block_number(),
+					Error::<T, I>::InvalidExpiry
+				);
+			}
+
+			Self::set_allowance(from, who, value)?;
+
+			if !value.is_zero() {
+				<AllowanceExpiry<T, I>>::set(from, who, expiry);
+			}
+
+			Self::deposit_event(Event::<T, I>::Approval {
+				from: from.clone(),
+				to: who.clone(),
+				value,
+			});
+
+			Ok(())
+		}
+
+		/// Bytes `owner` signs to let `spender` spend `value` of its tokens with
+		/// [`permit()`](Pallet::permit()) until `deadline`. Genesis hash and pallet index keep the
+		/// signature from being valid on another chain or for another instance, owner's current
+		/// [`PermitNonces`] entry keeps it from being valid twice
+		pub fn permit_payload(
+			owner: &T::AccountId,
+			spender: &T::AccountId,
+			value: T::Balance,
+			deadline: BlockNumberFor<T>,
+		) -> Vec<u8> {
+			Self::signing_payload(
+				PERMIT_TAG,
+				(owner, spender, value, Self::permit_nonce(owner), deadline),
+			)
+		}
+
+		/// Bytes `from` signs to let anyone transfer `value` of its tokens to `to` with
+		/// [`transfer_with_authorization()`](Pallet::transfer_with_authorization())
+		pub fn transfer_authorization_payload(
+			from: &T::AccountId,
+			to: &T::AccountId,
+			value: T::Balance,
+			valid_after: BlockNumberFor<T>,
+			valid_before: BlockNumberFor<T>,
+			nonce: &AuthorizationNonce,
+		) -> Vec<u8> {
+			Self::signing_payload(
+				TRANSFER_AUTHORIZATION_TAG,
+				(from, to, value, valid_after, valid_before, nonce),
+			)
+		}
+
+		/// Bytes `authorizer` signs to cancel its transfer authorization with
+		/// [`cancel_authorization()`](Pallet::cancel_authorization())
+		pub fn cancel_authorization_payload(
+			authorizer: &T::AccountId,
+			nonce: &AuthorizationNonce,
+		) -> Vec<u8> {
+			Self::signing_payload(CANCEL_AUTHORIZATION_TAG, (authorizer, nonce))
+		}
+
+		/// Prepends `tag`, genesis hash and pallet index to `data`. Signature over the result is
+		/// only valid for one kind of call on this chain and this instance
+		fn signing_payload(tag: &[u8], data: impl Encode) -> Vec<u8> {
+			let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+			let instance = <Self as PalletInfoAccess>::index() as u32;
+
+			(tag, genesis_hash, instance, data).encode()
+		}
+
+		pub fn is_authorization_used(
+			authorizer: &T::AccountId,
+			nonce: &AuthorizationNonce,
+		) -> bool {
+			<Authorizations<T, I>>::contains_key(authorizer, nonce)
+		}
+
+		/// Checks that `authorizer` signed `payload` and marks `nonce` as used, so the same
+		/// authorization can't be used or canceled again
+		fn use_authorization(
+			authorizer: &T::AccountId,
+			nonce: AuthorizationNonce,
+			payload: &[u8],
+			signature: &T::OffchainSignature,
+		) -> DispatchResult {
+			ensure!(
+				!Self::is_authorization_used(authorizer, &nonce),
+				Error::<T, I>::AuthorizationUsed
+			);
+			ensure!(signature.verify(payload, authorizer), Error::<T, I>::BadSignature);
+
+			<Authorizations<T, I>>::insert(authorizer, nonce, ());
+
+			Ok(())
+		}
+
+		/// Destroys `amount` tokens of `from`. If less than `MinBalance` is left, that dust is
+		/// destroyed as well
+		pub fn _burn(from: &T::AccountId, amount: T::Balance) -> DispatchResult {
+			if amount.is_zero() {
+				return Ok(())
+			}
+
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(from)?;
+
+			let (balance, amount) =
+				Self::checked_debit(Self::balance_of(from), amount, Preservation::Expendable)?;
+
+			ensure!(balance >= Self::locked_balance(from), Error::<T, I>::BalanceLocked);
+
+			Self::update_balance(from, balance);
+
+			<TotalSupply<T, I>>::mutate(|supply| {
+				*supply = supply.saturating_sub(amount);
+			});
+
+			T::OnTransfer::on_burn(from, amount);
+
+			Self::deposit_event(Event::Burned { from: from.clone(), value: amount });
+
+			Ok(())
+		}
+
+		/// Moves `amount` tokens from `from` to `to` and returns how much was actually moved. If
+		/// less than `MinBalance` would be left on `from`, it's either swept to `to` along with
+		/// `amount` when `preservation` is [`Preservation::Expendable`], or transfer fails
+		pub fn _transfer(
+			from: &T::AccountId,
+			to: &T::AccountId,
+			amount: T::Balance,
+			preservation: Preservation,
+		) -> Result<T::Balance, DispatchError> {
+			if amount.is_zero() {
+				return Ok(amount)
+			}
+
+			// Every path that moves tokens ends up here, so pause and freeze are enforced once
+			Self::ensure_not_paused()?;
+			Self::ensure_not_frozen(from)?;
+			Self::ensure_not_frozen(to)?;
+
+			let (from_balance, amount) =
+				Self::checked_debit(Self::balance_of(from), amount, preservation)?;
+
+			ensure!(from_balance >= Self::locked_balance(from), Error::<T, I>::BalanceLocked);
+
+			// Amount is final here, dust that goes along with it is checked as well
+			T::BeforeTransfer::before_transfer(from, to, amount)?;
+
+			Self::update_balance(from, from_balance);
+
+			// Receiver is read only after sender is written, so transfer to self changes nothing
+			let to_balance = Self::checked_credit(Self::balance_of(to), amount)?;
+
+			Self::update_balance(to, to_balance);
+
+			T::OnTransfer::on_transfer(from, to, amount);
+
 			// Produce event if successful
 			Self::deposit_event(Event::Transfer {
 				from: from.clone(),
@@ -335,7 +2067,52 @@ pub mod pallet {
 				value: amount,
 			});
 
-			Ok(())
+			Ok(amount)
+		}
+
+		/// Takes `amount` from `balance` and returns what's left along with how much was actually
+		/// taken. Remainder below `MinBalance` is taken as well if `preservation` allows it.
+		/// Balance rules of every token in this pallet come down to this and
+		/// [`checked_credit()`](Pallet::checked_credit())
+		pub fn checked_debit(
+			balance: T::Balance,
+			amount: T::Balance,
+			preservation: Preservation,
+		) -> Result<(T::Balance, T::Balance), DispatchError> {
+			ensure!(balance >= amount, Error::<T, I>::NotEnoughFunds);
+
+			let amount = if balance.sub(amount) >= T::MinBalance::get() {
+				amount
+			} else {
+				ensure!(preservation == Preservation::Expendable, Error::<T, I>::WouldKillAccount);
+
+				balance
+			};
+
+			Ok((balance.sub(amount), amount))
+		}
+
+		/// Adds `amount` to `balance`, making sure result is at least `MinBalance`
+		pub fn checked_credit(
+			balance: T::Balance,
+			amount: T::Balance,
+		) -> Result<T::Balance, DispatchError> {
+			let balance = balance.checked_add(&amount).ok_or(Error::<T, I>::ReceiverOverflow)?;
+			ensure!(balance >= T::MinBalance::get(), Error::<T, I>::BelowMinBalance);
+
+			Ok(balance)
+		}
+
+		/// Supply after `value` is issued, fails if it goes over `cap`
+		pub fn checked_issuance(
+			supply: T::Balance,
+			cap: T::Balance,
+			value: T::Balance,
+		) -> Result<T::Balance, DispatchError> {
+			let supply = supply.checked_add(&value).ok_or(Error::<T, I>::NotEnoughSupply)?;
+			ensure!(supply <= cap, Error::<T, I>::NotEnoughSupply);
+
+			Ok(supply)
 		}
 	}
 }
@@ -0,0 +1,176 @@
+//! Storage migrations of the ERC20 pallet
+
+use super::*;
+use codec::{Compact, Decode};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{OnRuntimeUpgrade, StorageVersion},
+};
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+pub mod v1 {
+	use super::*;
+
+	/// Before V1 genesis stored SCALE-encoded strings in [`Name`] and [`Symbol`], so every value had
+	/// a length prefix in front of its UTF-8 bytes. This migration strips it
+	pub struct MigrateToV1<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV1<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = StorageVersion::get::<Pallet<T, I>>();
+
+			if on_chain_version != 0 {
+				log::info!(
+					target: LOG_TARGET,
+					"skipping v1 migration, on-chain storage version is {:?}",
+					on_chain_version
+				);
+
+				return T::DbWeight::get().reads(1)
+			}
+
+			<Name<T, I>>::mutate(strip_length_prefix);
+			<Symbol<T, I>>::mutate(strip_length_prefix);
+
+			StorageVersion::new(1).put::<Pallet<T, I>>();
+
+			log::info!(target: LOG_TARGET, "migrated token name and symbol to raw UTF-8");
+
+			T::DbWeight::get().reads_writes(3, 3)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			ensure!(StorageVersion::get::<Pallet<T, I>>() == 1, "storage version must be 1");
+			ensure!(
+				core::str::from_utf8(&<Name<T, I>>::get()).is_ok(),
+				"token name must be valid UTF-8"
+			);
+			ensure!(
+				core::str::from_utf8(&<Symbol<T, I>>::get()).is_ok(),
+				"token symbol must be valid UTF-8"
+			);
+
+			Ok(())
+		}
+	}
+
+	/// Removes compact length prefix from `value`. Genesis used to truncate values that didn't fit
+	/// into the bound, so prefix may claim more bytes than there are left
+	fn strip_length_prefix<S: Get<u32>>(value: &mut BoundedVec<u8, S>) {
+		let mut input = &value[..];
+
+		let Ok(Compact(len)) = Compact::<u32>::decode(&mut input) else { return };
+
+		let truncated = value.len() as u32 == S::get() && (input.len() as u32) < len;
+
+		// Leave values that don't look like encoded strings untouched
+		if (input.len() as u32 != len && !truncated) || core::str::from_utf8(input).is_err() {
+			return
+		}
+
+		*value = BoundedVec::truncate_from(input.to_vec());
+	}
+}
+
+pub mod v2 {
+	use super::*;
+
+	/// Before V2 [`TotalSupply`] held amount of tokens that were left to issue, now it holds amount
+	/// that is in circulation. This migration counts it again from [`Balances`]
+	pub struct MigrateToV2<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV2<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = StorageVersion::get::<Pallet<T, I>>();
+
+			if on_chain_version != 1 {
+				log::info!(
+					target: LOG_TARGET,
+					"skipping v2 migration, on-chain storage version is {:?}",
+					on_chain_version
+				);
+
+				return T::DbWeight::get().reads(1)
+			}
+
+			let (accounts, supply) = circulating_supply::<T, I>();
+
+			<TotalSupply<T, I>>::put(supply);
+
+			StorageVersion::new(2).put::<Pallet<T, I>>();
+
+			log::info!(
+				target: LOG_TARGET,
+				"counted {:?} tokens in circulation on {} accounts",
+				supply,
+				accounts
+			);
+
+			T::DbWeight::get().reads_writes(accounts.saturating_add(1), 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			ensure!(StorageVersion::get::<Pallet<T, I>>() == 2, "storage version must be 2");
+			ensure!(
+				<TotalSupply<T, I>>::get() == circulating_supply::<T, I>().1,
+				"total supply must match the sum of balances"
+			);
+
+			Ok(())
+		}
+	}
+
+	/// Number of accounts that hold tokens and sum of their balances
+	fn circulating_supply<T: Config<I>, I: 'static>() -> (u64, T::Balance) {
+		<Balances<T, I>>::iter_values().fold((0, Zero::zero()), |(accounts, supply), balance| {
+			(accounts.saturating_add(1), supply.saturating_add(balance))
+		})
+	}
+}
+
+pub mod v3 {
+	use super::*;
+
+	/// Before V3 [`Decimals`] weren't stored and runtime reported a constant instead. This
+	/// migration stores `D` unless decimals are already set
+	pub struct MigrateToV3<T, D, I = ()>(PhantomData<(T, D, I)>);
+
+	impl<T: Config<I>, D: Get<u8>, I: 'static> OnRuntimeUpgrade for MigrateToV3<T, D, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = StorageVersion::get::<Pallet<T, I>>();
+
+			if on_chain_version != 2 {
+				log::info!(
+					target: LOG_TARGET,
+					"skipping v3 migration, on-chain storage version is {:?}",
+					on_chain_version
+				);
+
+				return T::DbWeight::get().reads(1)
+			}
+
+			if !<Decimals<T, I>>::exists() {
+				<Decimals<T, I>>::put(D::get());
+
+				log::info!(target: LOG_TARGET, "stored {} token decimals", D::get());
+			}
+
+			StorageVersion::new(3).put::<Pallet<T, I>>();
+
+			T::DbWeight::get().reads_writes(2, 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			ensure!(StorageVersion::get::<Pallet<T, I>>() == 3, "storage version must be 3");
+			ensure!(<Decimals<T, I>>::exists(), "token decimals must be stored");
+
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,62 @@
+//! Roles that let accounts make privileged calls without going through [`Config::ForceOrigin`].
+//! Roles are granted and revoked with [`grant_role()`](Pallet::grant_role()) and
+//! [`revoke_role()`](Pallet::revoke_role()), `ForceOrigin` passes every role check
+
+use super::*;
+use frame_support::traits::{EnsureOrigin, Get};
+use sp_std::marker::PhantomData;
+
+/// Privileges that can be granted to an account
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum Role {
+	/// Grants and revokes roles, manages supply cap, metadata, frozen accounts and snapshots
+	Admin,
+	/// Issues new tokens
+	Minter,
+	/// Burns tokens of any account
+	Burner,
+	/// Pauses and unpauses the token
+	Pauser,
+}
+
+frame_support::parameter_types! {
+	/// [`Role::Admin`] to be used with [`EnsureErcRole`]
+	pub const AdminRole: Role = Role::Admin;
+	/// [`Role::Minter`] to be used with [`EnsureErcRole`]
+	pub const MinterRole: Role = Role::Minter;
+	/// [`Role::Burner`] to be used with [`EnsureErcRole`]
+	pub const BurnerRole: Role = Role::Burner;
+	/// [`Role::Pauser`] to be used with [`EnsureErcRole`]
+	pub const PauserRole: Role = Role::Pauser;
+}
+
+/// Passes for [`Config::ForceOrigin`] and for signed accounts that hold role `R` in instance `I`.
+/// Succeeds with `None` for the former and with the account for the latter, so it can be used as
+/// an origin check by other pallets too
+pub struct EnsureErcRole<T, R, I = ()>(PhantomData<(T, R, I)>);
+
+impl<T: Config<I>, R: Get<Role>, I: 'static> EnsureOrigin<T::RuntimeOrigin>
+	for EnsureErcRole<T, R, I>
+{
+	type Success = Option<T::AccountId>;
+
+	fn try_origin(o: T::RuntimeOrigin) -> Result<Self::Success, T::RuntimeOrigin> {
+		let o = match T::ForceOrigin::try_origin(o) {
+			Ok(_) => return Ok(None),
+			Err(o) => o,
+		};
+
+		let o: Result<frame_system::RawOrigin<T::AccountId>, T::RuntimeOrigin> = o.into();
+
+		o.and_then(|o| match o {
+			frame_system::RawOrigin::Signed(who) if Pallet::<T, I>::has_role(R::get(), &who) =>
+				Ok(Some(who)),
+			o => Err(o.into()),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<T::RuntimeOrigin, ()> {
+		T::ForceOrigin::try_successful_origin()
+	}
+}
@@ -0,0 +1,54 @@
+//! Runtime API definition for the ERC20 pallet. Lets clients query token state without knowing how
+//! it's laid out in storage
+
+use crate::SnapshotId;
+use codec::Codec;
+use sp_runtime::traits::NumberFor;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	#[api_version(4)]
+	pub trait Erc20Api<AccountId, Balance>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// Balance of `who`
+		fn balance_of(who: AccountId) -> Balance;
+
+		/// How much `spender` is allowed to transfer on behalf of `owner`
+		fn allowance(owner: AccountId, spender: AccountId) -> Balance;
+
+		/// Amount of tokens in circulation
+		fn total_supply() -> Balance;
+
+		/// Token name, UTF-8 bytes
+		fn name() -> Vec<u8>;
+
+		/// Token symbol, UTF-8 bytes
+		fn symbol() -> Vec<u8>;
+
+		/// Number of decimals used to display token amounts
+		fn decimals() -> u8;
+
+		/// Balance `who` had at snapshot `id`, `None` if there's no such snapshot
+		#[api_version(2)]
+		fn balance_of_at(who: AccountId, id: SnapshotId) -> Option<Balance>;
+
+		/// Total supply at snapshot `id`, `None` if there's no such snapshot
+		#[api_version(2)]
+		fn total_supply_at(id: SnapshotId) -> Option<Balance>;
+
+		/// How much can still be issued in the current window, `None` if issuance isn't limited
+		#[api_version(3)]
+		fn remaining_issuance() -> Option<Balance>;
+
+		/// Voting power delegated to `who`
+		#[api_version(4)]
+		fn get_votes(who: AccountId) -> Balance;
+
+		/// Voting power `who` had at the end of `block`, `None` unless `block` is already over
+		#[api_version(4)]
+		fn get_past_votes(who: AccountId, block: NumberFor<Block>) -> Option<Balance>;
+	}
+}
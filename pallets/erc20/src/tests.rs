@@ -1,11 +1,22 @@
 use frame_benchmarking::whitelisted_caller;
-use crate::{self as pallet_erc20, Config};
-use frame_support::{derive_impl, parameter_types};
+use crate::{self as pallet_erc20, BeforeTransfer, Config, Instance1, OnTransfer};
+use frame_support::{derive_impl, ensure, parameter_types};
 use frame_support::traits::ConstU32;
-use sp_runtime::{BuildStorage};
+use sp_runtime::{
+	testing::{TestSignature, UintAuthorityId},
+	BuildStorage, DispatchError, DispatchResult,
+};
 // Const that are needed for this pallet Config
 pub const MAX_NAME_LENGTH: u8 = 50;
 pub const MAX_SYMBOL_LENGTH: u8 = 50;
+pub const MAX_URI_LENGTH: u32 = 128;
+pub const MAX_DESCRIPTION_LENGTH: u32 = 256;
+pub const MAX_BATCH_TRANSFERS: u32 = 128;
+pub const MAX_VESTING_SCHEDULES: u32 = 3;
+pub const MIN_VESTED_TRANSFER: u32 = 20;
+pub const MIN_BALANCE: u32 = 10;
+pub const ALLOWANCE_DEPOSIT: u64 = 5;
+pub const ASSET_DEPOSIT: u64 = 20;
 pub const TOTAL_SUPPLY: u32 = u32::MAX;
 
 
@@ -17,7 +28,62 @@ pub type Balance = u32;
 parameter_types! {
 	pub const MaxNameLength: u8 = MAX_NAME_LENGTH;
 	pub const MaxSymbolLength: u8 = MAX_SYMBOL_LENGTH;
+	pub const MaxUriLength: u32 = MAX_URI_LENGTH;
+	pub const MaxDescriptionLength: u32 = MAX_DESCRIPTION_LENGTH;
+	pub const MaxBatchTransfers: u32 = MAX_BATCH_TRANSFERS;
+	pub const MaxVestingSchedules: u32 = MAX_VESTING_SCHEDULES;
+	pub const MinVestedTransfer: Balance = MIN_VESTED_TRANSFER;
+	pub const MinBalance: Balance = MIN_BALANCE;
+	pub const AllowanceDeposit: u64 = ALLOWANCE_DEPOSIT;
+	pub const AssetDeposit: u64 = ASSET_DEPOSIT;
 	pub static ExistentialDeposit: u64 = 1;
+	/// Every movement reported to [`RecordMovements`] as `(from, to, value)`, `None` stands for
+	/// issuance or burn
+	pub static Movements: Vec<(Option<u64>, Option<u64>, Balance)> = Vec::new();
+	/// Account that [`BlockRecipient`] doesn't let receive tokens
+	pub static BlockedRecipient: Option<u64> = None;
+}
+
+pub struct RecordMovements;
+
+impl OnTransfer<u64, Balance> for RecordMovements {
+	fn on_transfer(from: &u64, to: &u64, value: Balance) {
+		Movements::mutate(|log| log.push((Some(*from), Some(*to), value)));
+	}
+
+	fn on_issue(to: &u64, value: Balance) {
+		Movements::mutate(|log| log.push((None, Some(*to), value)));
+	}
+
+	fn on_burn(from: &u64, value: Balance) {
+		Movements::mutate(|log| log.push((Some(*from), None, value)));
+	}
+}
+
+pub struct BlockRecipient;
+
+impl BeforeTransfer<u64, Balance> for BlockRecipient {
+	fn before_transfer(_from: &u64, to: &u64, _value: Balance) -> DispatchResult {
+		ensure!(BlockedRecipient::get() != Some(*to), DispatchError::Other("Recipient is blocked"));
+
+		Ok(())
+	}
+}
+
+/// Signs permits in benchmarks. [`TestSignature`] is valid as long as it names the signer and
+/// carries the exact message
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TestSigner;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<u64, UintAuthorityId, TestSignature> for TestSigner {
+	fn signer() -> (UintAuthorityId, u64) {
+		(UintAuthorityId(1), 1)
+	}
+
+	fn sign(signer: &UintAuthorityId, message: &[u8]) -> TestSignature {
+		TestSignature(signer.0, message.to_vec())
+	}
 }
 
 
@@ -26,14 +92,22 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system,
+		Balances: pallet_balances,
         Sudo: pallet_sudo,
 		Erc: pallet_erc20,
+		StableErc: pallet_erc20::<Instance1>,
 	}
 );
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
 impl frame_system::Config for Test {
 	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
 }
 
 
@@ -49,11 +123,52 @@ impl Config for Test {
 	type Supply = ConstU32<TOTAL_SUPPLY>;
 	type MaxNameLength = MaxNameLength;
 	type MaxSymbolLength = MaxSymbolLength;
+	type MaxUriLength = MaxUriLength;
+	type MaxDescriptionLength = MaxDescriptionLength;
+	type MaxBatchTransfers = MaxBatchTransfers;
+	type MaxVestingSchedules = MaxVestingSchedules;
+	type MinVestedTransfer = MinVestedTransfer;
+	type OnTransfer = (RecordMovements, ());
+	type BeforeTransfer = (BlockRecipient,);
+	type OffchainSignature = TestSignature;
+	type OffchainPublic = UintAuthorityId;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TestSigner;
+	type MinBalance = MinBalance;
+	type Currency = Balances;
+	type AllowanceDeposit = AllowanceDeposit;
+	type AssetDeposit = AssetDeposit;
 	type ForceOrigin = frame_system::EnsureRoot<<Test as frame_system::Config>::AccountId>;
 	
 	type Balance = Balance;
 }
 
+impl Config<Instance1> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Supply = ConstU32<TOTAL_SUPPLY>;
+	type MaxNameLength = MaxNameLength;
+	type MaxSymbolLength = MaxSymbolLength;
+	type MaxUriLength = MaxUriLength;
+	type MaxDescriptionLength = MaxDescriptionLength;
+	type MaxBatchTransfers = MaxBatchTransfers;
+	type MaxVestingSchedules = MaxVestingSchedules;
+	type MinVestedTransfer = MinVestedTransfer;
+	type OnTransfer = ();
+	type BeforeTransfer = ();
+	type OffchainSignature = TestSignature;
+	type OffchainPublic = UintAuthorityId;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TestSigner;
+	type MinBalance = MinBalance;
+	type Currency = Balances;
+	type AllowanceDeposit = AllowanceDeposit;
+	type AssetDeposit = AssetDeposit;
+	type ForceOrigin = frame_system::EnsureRoot<<Test as frame_system::Config>::AccountId>;
+
+	type Balance = Balance;
+}
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
@@ -66,20 +181,39 @@ impl ExtBuilder {
 	pub fn build(self) -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 
+		// Native currency is only needed to reserve allowance deposits
+		pallet_balances::GenesisConfig::<Test> {
+			balances: vec![(whitelisted_caller(), 100), (2, 100), (3, 100)],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
 		pallet_erc20::GenesisConfig::<Test> {
             balances: vec![
 				(whitelisted_caller(), 2000),
 				(1, 0)
 			],
             allowances: vec![],
-            total_supply: Balance::MAX,
+            vesting: vec![],
+            supply_cap: None,
             name: "Ethereum".to_string(),
             symbol: "ETH".to_string(),
+            decimals: 18,
             _ignore: Default::default()
         }
 			.assimilate_storage(&mut t)
 			.unwrap();
 
+		pallet_erc20::GenesisConfig::<Test, Instance1> {
+			balances: vec![(whitelisted_caller(), 500)],
+			name: "Stable Dollar".to_string(),
+			symbol: "SUSD".to_string(),
+			decimals: 6,
+			..Default::default()
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
 		let mut ext = sp_io::TestExternalities::new(t);
 		ext.execute_with(|| System::set_block_number(1));
 		ext
@@ -92,6 +226,1138 @@ impl Default for ExtBuilder {
 	}
 }
 
+#[test]
+fn all_storage_items_are_bounded() {
+	use frame_support::traits::StorageInfoTrait;
+
+	for info in Erc::storage_info() {
+		assert!(
+			info.max_size.is_some(),
+			"{} has no max size",
+			String::from_utf8_lossy(&info.storage_name)
+		);
+	}
+}
+
+#[test]
+fn fungible_traits_follow_pallet_rules() {
+	use frame_support::{
+		assert_noop, assert_ok,
+		traits::{
+			fungible::{Inspect, Mutate},
+			tokens::Preservation,
+		},
+	};
+	use sp_runtime::ArithmeticError;
+
+	ExtBuilder::default().build().execute_with(|| {
+		let caller: u64 = whitelisted_caller();
+
+		assert_ok!(<Erc as Mutate<_>>::transfer(&caller, &1, 500, Preservation::Expendable));
+		assert_eq!(<Erc as Inspect<_>>::balance(&caller), 1500);
+		assert_eq!(<Erc as Inspect<_>>::balance(&1), 500);
+
+		assert_ok!(<Erc as Mutate<_>>::mint_into(&1, 100));
+		assert_eq!(<Erc as Inspect<_>>::total_issuance(), 2100);
+
+		// Minting through fungible traits can't go over the cap either
+		assert_ok!(Erc::set_supply_cap(RuntimeOrigin::root(), 2100));
+		assert_noop!(<Erc as Mutate<_>>::mint_into(&1, 1), ArithmeticError::Overflow);
+	});
+}
+
+#[test]
+fn balanced_deposits_respect_pause_and_freeze() {
+	use frame_support::{
+		assert_noop, assert_ok,
+		traits::{fungible::Balanced, tokens::Precision},
+	};
+	use sp_runtime::TokenError;
+
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Erc::pause(RuntimeOrigin::root()));
+		assert_noop!(<Erc as Balanced<_>>::deposit(&1, 100, Precision::Exact), TokenError::Blocked);
+
+		assert_ok!(Erc::unpause(RuntimeOrigin::root()));
+		assert_ok!(Erc::freeze(RuntimeOrigin::root(), 1));
+		assert_noop!(<Erc as Balanced<_>>::deposit(&1, 100, Precision::Exact), TokenError::Blocked);
+
+		// Dropped debt is new supply, reported as issue
+		assert_ok!(Erc::thaw(RuntimeOrigin::root(), 1));
+		drop(<Erc as Balanced<_>>::deposit(&1, 100, Precision::Exact));
+
+		assert_eq!(Erc::balance_of(1), 100);
+		assert_eq!(Erc::total_supply(), 2100);
+		assert_eq!(Movements::get(), vec![(None, Some(1), 100)]);
+	});
+}
+
+#[test]
+fn genesis_stores_raw_utf8_metadata() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(String::from_utf8(Erc::name()).unwrap(), "Ethereum");
+		assert_eq!(String::from_utf8(Erc::symbol()).unwrap(), "ETH");
+	});
+}
+
+#[test]
+fn migration_to_v1_strips_length_prefix() {
+	use codec::Encode;
+	use frame_support::{
+		traits::{OnRuntimeUpgrade, StorageVersion},
+		BoundedVec,
+	};
+
+	ExtBuilder::default().build().execute_with(|| {
+		// Storage as it was written by genesis before V1
+		StorageVersion::new(0).put::<Erc>();
+		pallet_erc20::Name::<Test>::put(BoundedVec::truncate_from("Ethereum".encode()));
+		pallet_erc20::Symbol::<Test>::put(BoundedVec::truncate_from("ETH".encode()));
+
+		pallet_erc20::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Erc::name(), b"Ethereum".to_vec());
+		assert_eq!(Erc::symbol(), b"ETH".to_vec());
+		assert_eq!(StorageVersion::get::<Erc>(), 1);
+	});
+}
+
+#[test]
+fn expired_allowances_are_rejected_and_cleaned_up() {
+	use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight};
+
+	ExtBuilder::default().build().execute_with(|| {
+		let owner: u64 = whitelisted_caller();
+
+		assert_ok!(Erc::approve(RuntimeOrigin::signed(owner), 2, 100, Some(5)));
+		assert_ok!(Erc::transfer_from(RuntimeOrigin::signed(2), owner, 3, 10));
+
+		System::set_block_number(5);
+
+		assert_noop!(
+			Erc::transfer_from(RuntimeOrigin::signed(2), owner, 3, 10),
+			crate::Error::<Test>::AllowanceExpired
+		);
+
+		Erc::on_idle(5, Weight::MAX);
+
+		assert_eq!(Erc::allowance_of(owner, 2), 0);
+		assert_eq!(Erc::allowance_expiry(owner, 2), None);
+	});
+}
+
+#[test]
+fn transfer_many_is_all_or_nothing() {
+	use frame_support::{assert_noop, assert_ok, traits::UnfilteredDispatchable};
+
+	ExtBuilder::default().build().execute_with(|| {
+		let owner: u64 = whitelisted_caller();
+
+		// Second transfer exceeds remaining balance, so the first one must be reverted too
+		let call = crate::Call::<Test>::transfer_many { recipients: vec![(2, 1500), (3, 1000)] };
+		assert_noop!(
+			call.dispatch_bypass_filter(RuntimeOrigin::signed(owner)),
+			crate::Error::<Test>::NotEnoughFunds
+		);
+
+		let call = crate::Call::<Test>::transfer_many { recipients: vec![(2, 1500), (3, 500)] };
+		assert_ok!(call.dispatch_bypass_filter(RuntimeOrigin::signed(owner)));
+
+		assert_eq!(Erc::balance_of(owner), 0);
+		assert_eq!(Erc::balance_of(2), 1500);
+		assert_eq!(Erc::balance_of(3), 500);
+
+		let recipients = vec![(2, 0); MAX_BATCH_TRANSFERS as usize + 1];
+		assert_noop!(
+			Erc::transfer_many(RuntimeOrigin::signed(owner), recipients),
+			crate::Error::<Test>::TooManyTransfers
+		);
+	});
+}
+
+#[test]
+fn snapshots_keep_historical_balances() {
+	use frame_support::assert_ok;
+
+	ExtBuilder::default().build().execute_with(|| {
+		let owner: u64 = whitelisted_caller();
+
+		assert_eq!(Erc::balance_of_at(&owner, 1), None);
+
+		assert_ok!(Erc::snapshot(RuntimeOrigin::root()));
+		assert_eq!(Erc::current_snapshot_id(), 1);
+
+		assert_ok!(Erc::transfer(RuntimeOrigin::signed(owner), 2, 500));
+		assert_ok!(Erc::transfer(RuntimeOrigin::signed(owner), 2, 500));
+		assert_ok!(Erc::issue(RuntimeOrigin::root(), 3, 100));
+
+		assert_ok!(Erc::snapshot(RuntimeOrigin::root()));
+		assert_ok!(Erc::burn(RuntimeOrigin::signed(2), 300));
+
+		// Only the value from before the first change is kept for each snapshot
+		assert_eq!(Erc::balance_of_at(&owner, 1), Some(2000));
+		assert_eq!(Erc::balance_of_at(&2, 1), Some(0));
+		assert_eq!(Erc::total_supply_at(1), Some(2000));
+
+		assert_eq!(Erc::balance_of_at(&owner, 2), Some(1000));
+		assert_eq!(Erc::balance_of_at(&2, 2), Some(1000));
+		assert_eq!(Erc::balance_of_at(&3, 2), Some(100));
+		assert_eq!(Erc::total_supply_at(2), Some(2100));
+
+		assert_eq!(Erc::balance_of(2), 700);
+		assert_eq!(Erc::balance_of_at(&2, 3), None);
+
+		assert_ok!(Erc::snapshot(RuntimeOrigin::root()));
+		assert_ok!(Erc::transfer(RuntimeOrigin::signed(owner), 3, 100));
+		assert_ok!(Erc::snapshot(RuntimeOrigin::root()));
+		assert_ok!(Erc::snapshot(RuntimeOrigin::root()));
+		assert_ok!(Erc::transfer(RuntimeOrigin::signed(owner), 3, 100));
+
+		// Owner has records of snapshots 1, 3 and 5, snapshot 4 is answered by the one after it
+		assert_eq!(crate::AccountSnapshotCount::<Test>::get(owner), 3);
+		assert_eq!(Erc::balance_of_at(&owner, 2), Some(1000));
+		assert_eq!(Erc::balance_of_at(&owner, 3), Some(1000));
+		assert_eq!(Erc::balance_of_at(&owner, 4), Some(900));
+		assert_eq!(Erc::balance_of_at(&owner, 5), Some(900));
+		assert_eq!(Erc::balance_of(owner), 800);
+		assert_eq!(Erc::total_supply_at(4), Some(1800));
+		assert_eq!(Erc::total_supply_at(6), None);
+	});
+}
+
+#[test]
+fn dust_is_swept_or_rejected_and_empty_accounts_are_reaped() {
+	use frame_support::{assert_noop, assert_ok};
+
+	ExtBuilder::default().build().execute_with(|| {
+		let owner: u64 = whitelisted_caller();
+
+		assert_noop!(
+			Erc::transfer(RuntimeOrigin::signed(owner), 2, MIN_BALANCE - 1),
+			crate::Error::<Test>::BelowMinBalance
+		);
+		assert_noop!(
+			Erc::transfer_keep_alive(RuntimeOrigin::signed(owner), 2, 1995),
+			crate::Error::<Test>::WouldKillAccount
+		);
+
+		// 5 tokens left on owner's account are dust, so they go along with the transfer
+		assert_ok!(Erc::transfer(RuntimeOrigin::signed(owner), 2, 1995));
+		assert_eq!(Erc::balance_of(2), 2000);
+		assert!(!crate::Balances::<Test>::contains_key(owner));
+		System::assert_has_event(crate::Event::AccountReaped { who: owner }.into());
+
+		assert_ok!(Erc::transfer_all(RuntimeOrigin::signed(2), 3, true));
+		assert_eq!(Erc::balance_of(2), MIN_BALANCE);
+		assert_eq!(Erc::balance_of(3), 2000 - MIN_BALANCE);
+
+		assert_ok!(Erc::transfer_all(RuntimeOrigin::signed(3), 2, false));
+		assert!(!crate::Balances::<Test>::contains_key(3));
+		assert_eq!(Erc::total_supply(), 2000);
+	});
+}
+
+#[test]
+fn allowance_deposit_is_reserved_and_released() {
+	use frame_support::{assert_ok, traits::ReservableCurrency};
+
+	ExtBuilder::default().build().execute_with(|| {
+		let owner: u64 = whitelisted_caller();
+
+		assert_ok!(Erc::approve(RuntimeOrigin::signed(owner), 2, 100, None));
+		assert_eq!(Balances::reserved_balance(owner), ALLOWANCE_DEPOSIT);
+
+		// Changing existing allowance doesn't take another deposit
+		assert_ok!(Erc::increase_allowance(RuntimeOrigin::signed(owner), 2, 50));
+		assert_ok!(Erc::approve(RuntimeOrigin::signed(owner), 3, 100, None));
+		assert_eq!(Balances::reserved_balance(owner), 2 * ALLOWANCE_DEPOSIT);
+
+		// Spent allowance returns the deposit, as well as revoked one
+		assert_ok!(Erc::transfer_from(RuntimeOrigin::signed(2), owner, 2, 150));
+		assert_ok!(Erc::approve(RuntimeOrigin::signed(owner), 3, 0, None));
+		assert_eq!(Balances::reserved_balance(owner), 0);
+		assert_eq!(crate::AllowanceDeposits::<Test>::iter().count(), 0);
+
+		// Account without native funds can't create allowances
+		assert!(Erc::approve(RuntimeOrigin::signed(4), 2, 100, None).is_err());
+	});
+}
+
+#[test]
+fn instances_have_separate_storage_and_events() {
+	use frame_support::assert_ok;
+
+	ExtBuilder::default().build().execute_with(|| {
+		let caller: u64 = whitelisted_caller();
+
+		assert_eq!(Erc::total_supply(), 2000);
+		assert_eq!(StableErc::total_supply(), 500);
+		assert_eq!(StableErc::symbol(), b"SUSD".to_vec());
+
+		assert_ok!(StableErc::transfer(RuntimeOrigin::signed(caller), 2, 100));
+		assert_eq!(StableErc::balance_of(2), 100);
+		assert_eq!(Erc::balance_of(2), 0);
+		assert_eq!(Erc::balance_of(caller), 2000);
+
+		System::assert_last_event(RuntimeEvent::StableErc(crate::Event::Transfer {
+			from: caller,
+			to: 2,
+			value: 100,
+		}));
+		assert!(!System::events().iter().any(|r| matches!(r.event, RuntimeEvent::Erc(_))));
+
+		// Pausing one token leaves the other usable
+		assert_ok!(Erc::pause(RuntimeOrigin::root()));
+		assert!(Erc::transfer(RuntimeOrigin::signed(caller), 2, 100).is_err());
+		assert_ok!(StableErc::transfer(RuntimeOrigin::signed(caller), 2, 100));
+		assert_eq!(StableErc::balance_of(2), 200);
+	});
+}
+
+#[test]
+fn anyone_can_create_and_manage_own_token() {
+	use frame_support::{assert_noop, assert_ok, traits::ReservableCurrency};
+
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = 2;
+
+		assert_ok!(Erc::create(
+			RuntimeOrigin::signed(owner),
+			b"Token".to_vec(),
+			b"TKN".to_vec(),
+			6,
+			1000
+		));
+		assert_eq!(Balances::reserved_balance(owner), ASSET_DEPOSIT);
+		assert_eq!(Erc::next_asset_id(), 1);
+
+		// Only owner issues the token and only within its cap
+		assert_noop!(
+			Erc::mint_asset(RuntimeOrigin::signed(3), 0, 3, 500),
+			crate::Error::<Test>::NotAssetOwner
+		);
+		assert_noop!(
+			Erc::mint_asset(RuntimeOrigin::signed(owner), 0, 3, 1001),
+			crate::Error::<Test>::NotEnoughSupply
+		);
+		assert_ok!(Erc::mint_asset(RuntimeOrigin::signed(owner), 0, 3, 500));
+
+		// Balance rules are the same as for the instance's own token
+		assert_noop!(
+			Erc::transfer_asset(RuntimeOrigin::signed(3), 0, 4, MIN_BALANCE - 1),
+			crate::Error::<Test>::BelowMinBalance
+		);
+		assert_ok!(Erc::transfer_asset(RuntimeOrigin::signed(3), 0, 4, 100));
+		assert_ok!(Erc::approve_asset(RuntimeOrigin::signed(3), 0, owner, 50));
+		assert_ok!(Erc::transfer_asset_from(RuntimeOrigin::signed(owner), 0, 3, 4, 50));
+		assert_eq!(Erc::asset_balance_of(0, 3), 350);
+		assert_eq!(Erc::asset_balance_of(0, 4), 150);
+		assert_eq!(Balances::reserved_balance(3), 0);
+
+		// Instance's own token isn't affected
+		assert_eq!(Erc::balance_of(3), 0);
+		assert_eq!(Erc::total_supply(), 2000);
+
+		assert_noop!(
+			Erc::set_asset_supply_cap(RuntimeOrigin::signed(owner), 0, 499),
+			crate::Error::<Test>::CapBelowSupply
+		);
+
+		// Deposit follows the ownership without touching free balance of the new owner
+		let free = Balances::free_balance(3);
+		assert_ok!(Erc::transfer_asset_ownership(RuntimeOrigin::signed(owner), 0, 3));
+		assert_eq!(Balances::reserved_balance(owner), 0);
+		assert_eq!(Balances::reserved_balance(3), ASSET_DEPOSIT);
+		assert_eq!(Balances::free_balance(3), free);
+		assert_noop!(
+			Erc::set_asset_supply_cap(RuntimeOrigin::signed(owner), 0, 600),
+			crate::Error::<Test>::NotAssetOwner
+		);
+		assert_ok!(Erc::set_asset_supply_cap(RuntimeOrigin::signed(3), 0, 600));
+
+		assert_noop!(
+			Erc::transfer_asset(RuntimeOrigin::signed(3), 1, 4, 100),
+			crate::Error::<Test>::UnknownAsset
+		);
+
+		// Spender is charged for dust that goes along with the tokens it moves
+		assert_ok!(Erc::transfer_asset(RuntimeOrigin::signed(3), 0, 4, 335));
+		assert_ok!(Erc::approve_asset(RuntimeOrigin::signed(3), 0, owner, 10));
+		assert_noop!(
+			Erc::transfer_asset_from(RuntimeOrigin::signed(owner), 0, 3, owner, 10),
+			crate::Error::<Test>::NotEnoughAllowance
+		);
+		assert_ok!(Erc::approve_asset(RuntimeOrigin::signed(3), 0, owner, 15));
+		assert_ok!(Erc::transfer_asset_from(RuntimeOrigin::signed(owner), 0, 3, owner, 10));
+		assert_eq!(Erc::asset_balance_of(0, 3), 0);
+		assert_eq!(Erc::asset_balance_of(0, owner), 15);
+		assert_eq!(Erc::asset_allowance_of(0, &3, &owner), 0);
+
+		// Token is destroyed only once all of it is burned, and owner gets the deposit back
+		assert_ok!(Erc::approve_asset(RuntimeOrigin::signed(owner), 0, 3, 10));
+		assert_noop!(
+			Erc::destroy_asset(RuntimeOrigin::signed(3), 0),
+			crate::Error::<Test>::AssetInCirculation
+		);
+		assert_ok!(Erc::burn_asset(RuntimeOrigin::signed(4), 0, 485));
+		assert_ok!(Erc::burn_asset(RuntimeOrigin::signed(owner), 0, 15));
+		assert_noop!(
+			Erc::destroy_asset(RuntimeOrigin::signed(owner), 0),
+			crate::Error::<Test>::NotAssetOwner
+		);
+		assert_ok!(Erc::destroy_asset(RuntimeOrigin::signed(3), 0));
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert!(Erc::asset(0).is_none());
+		System::assert_last_event(RuntimeEvent::Erc(crate::Event::AssetDestroyed { asset: 0 }));
+
+		// Allowances left on it can only be revoked
+		assert_noop!(
+			Erc::approve_asset(RuntimeOrigin::signed(owner), 0, 3, 20),
+			crate::Error::<Test>::UnknownAsset
+		);
+		assert_ok!(Erc::approve_asset(RuntimeOrigin::signed(owner), 0, 3, 0));
+		assert_eq!(Balances::reserved_balance(owner), 0);
+	});
+}
+
+#[test]
+fn hooks_observe_and_veto_movements() {
+	use frame_support::{assert_noop, assert_ok};
+
+	ExtBuilder::default().build().execute_with(|| {
+		let owner: u64 = whitelisted_caller();
+
+		assert_ok!(Erc::transfer(RuntimeOrigin::signed(owner), 2, 100));
+		assert_ok!(Erc::issue(RuntimeOrigin::root(), 3, 50));
+		assert_ok!(Erc::burn(RuntimeOrigin::signed(2), 20));
+
+		// Failed calls and other instances don't report anything
+		assert!(Erc::transfer(RuntimeOrigin::signed(3), 2, 500).is_err());
+		assert_ok!(StableErc::transfer(RuntimeOrigin::signed(owner), 2, 100));
+
+		assert_eq!(
+			Movements::get(),
+			vec![(Some(owner), Some(2), 100), (None, Some(3), 50), (Some(2), None, 20)]
+		);
+
+		BlockedRecipient::set(Some(3));
+
+		assert_noop!(
+			Erc::transfer(RuntimeOrigin::signed(owner), 3, 100),
+			DispatchError::Other("Recipient is blocked")
+		);
+		assert_ok!(Erc::transfer(RuntimeOrigin::signed(3), 2, 50));
+	});
+}
+
+#[test]
+fn roles_allow_privileged_calls_without_root() {
+	use crate::Role;
+	use frame_support::{assert_noop, assert_ok};
+	use sp_runtime::DispatchError::BadOrigin;
+
+	ExtBuilder::default().build().execute_with(|| {
+		let (admin, minter) = (2, 3);
+
+		assert_noop!(Erc::issue(RuntimeOrigin::signed(minter), minter, 100), BadOrigin);
+		assert_noop!(
+			Erc::grant_role(RuntimeOrigin::signed(admin), Role::Minter, minter),
+			BadOrigin
+		);
+
+		// Force origin stays the super-admin
+		assert_ok!(Erc::grant_role(RuntimeOrigin::root(), Role::Admin, admin));
+		assert_ok!(Erc::grant_role(RuntimeOrigin::signed(admin), Role::Minter, minter));
+		System::assert_last_event(
+			crate::Event::RoleGranted { role: Role::Minter, who: minter }.into(),
+		);
+
+		assert_ok!(Erc::issue(RuntimeOrigin::signed(minter), minter, 100));
+		assert_eq!(Erc::balance_of(minter), 100);
+
+		// Roles don't imply each other
+		assert_noop!(Erc::pause(RuntimeOrigin::signed(admin)), BadOrigin);
+		assert_noop!(Erc::force_burn(RuntimeOrigin::signed(minter), minter, 50), BadOrigin);
+
+		assert_ok!(Erc::revoke_role(RuntimeOrigin::signed(admin), Role::Minter, minter));
+		assert_noop!(Erc::issue(RuntimeOrigin::signed(minter), minter, 100), BadOrigin);
+
+		// Roles are per instance
+		assert!(!StableErc::has_role(Role::Admin, &admin));
+	});
+}
+
+#[test]
+fn issuance_is_limited_per_window() {
+	use frame_support::{assert_noop, assert_ok};
+
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(Erc::remaining_issuance(), None);
+
+		assert_noop!(
+			Erc::set_issuance_limit(RuntimeOrigin::root(), Some((100, 0))),
+			crate::Error::<Test>::ZeroIssuancePeriod
+		);
+		assert_ok!(Erc::set_issuance_limit(RuntimeOrigin::root(), Some((100, 10))));
+
+		assert_ok!(Erc::issue(RuntimeOrigin::root(), 2, 60));
+		assert_eq!(Erc::remaining_issuance(), Some(40));
+		assert_noop!(
+			Erc::issue(RuntimeOrigin::root(), 2, 41),
+			crate::Error::<Test>::IssuanceRateExceeded
+		);
+
+		// Window starts at block 1, so quota is restored at block 11
+		System::set_block_number(10);
+		assert_eq!(Erc::remaining_issuance(), Some(40));
+
+		System::set_block_number(11);
+		assert_eq!(Erc::remaining_issuance(), Some(100));
+		assert_ok!(Erc::issue(RuntimeOrigin::root(), 2, 100));
+		assert_eq!(Erc::remaining_issuance(), Some(0));
+
+		assert_ok!(Erc::set_issuance_limit(RuntimeOrigin::root(), None));
+		assert_ok!(Erc::issue(RuntimeOrigin::root(), 2, 1000));
+	});
+}
+
+#[test]
+fn vesting_locks_tokens_until_they_unlock() {
+	use crate::VestingSchedule;
+	use frame_support::{assert_noop, assert_ok};
+	use sp_runtime::DispatchError::BadOrigin;
+
+	ExtBuilder::default().build().execute_with(|| {
+		let (caller, (vested, other)) = (whitelisted_caller::<u64>(), (2, 3));
+		let schedule = VestingSchedule { locked: 1000, start: 1, cliff: 10, duration: 100 };
+
+		assert_noop!(
+			Erc::vested_transfer(
+				RuntimeOrigin::signed(caller),
+				vested,
+				VestingSchedule { cliff: 101, ..schedule }
+			),
+			crate::Error::<Test>::InvalidVestingSchedule
+		);
+
+		assert_ok!(Erc::vested_transfer(RuntimeOrigin::signed(caller), vested, schedule));
+		assert_eq!(Erc::balance_of(vested), 1000);
+		assert_eq!(Erc::locked_balance(&vested), 1000);
+		assert_noop!(
+			Erc::transfer(RuntimeOrigin::signed(vested), other, 1),
+			crate::Error::<Test>::BalanceLocked
+		);
+		assert_noop!(
+			Erc::burn(RuntimeOrigin::signed(vested), 1),
+			crate::Error::<Test>::BalanceLocked
+		);
+
+		// Tenth of the tokens unlocks at once when cliff is over
+		System::set_block_number(11);
+		assert_eq!(Erc::locked_balance(&vested), 900);
+		assert_ok!(Erc::transfer(RuntimeOrigin::signed(vested), other, 100));
+		assert_noop!(
+			Erc::transfer(RuntimeOrigin::signed(vested), other, 10),
+			crate::Error::<Test>::BalanceLocked
+		);
+
+		System::set_block_number(101);
+		assert_eq!(Erc::locked_balance(&vested), 0);
+		assert_ok!(Erc::vest(RuntimeOrigin::signed(vested)));
+		System::assert_last_event(crate::Event::VestingUpdated { who: vested, locked: 0 }.into());
+		assert!(Erc::vesting(vested).is_empty());
+		assert_noop!(Erc::vest(RuntimeOrigin::signed(vested)), crate::Error::<Test>::NotVesting);
+
+		// Tiny schedules aren't accepted and number of schedules per account is bounded
+		let schedule = VestingSchedule { locked: 20, start: 101, cliff: 0, duration: 10 };
+
+		assert_noop!(
+			Erc::vested_transfer(
+				RuntimeOrigin::signed(caller),
+				other,
+				VestingSchedule { locked: MIN_VESTED_TRANSFER - 1, ..schedule }
+			),
+			crate::Error::<Test>::AmountLow
+		);
+
+		for _ in 0..MAX_VESTING_SCHEDULES {
+			assert_ok!(Erc::vested_transfer(RuntimeOrigin::signed(caller), other, schedule));
+		}
+		assert_noop!(
+			Erc::vested_transfer(RuntimeOrigin::signed(caller), other, schedule),
+			crate::Error::<Test>::TooManyVestingSchedules
+		);
+
+		assert_noop!(Erc::force_remove_schedule(RuntimeOrigin::signed(other), other, 0), BadOrigin);
+		assert_noop!(
+			Erc::force_remove_schedule(RuntimeOrigin::root(), other, MAX_VESTING_SCHEDULES),
+			crate::Error::<Test>::UnknownVestingSchedule
+		);
+		assert_ok!(Erc::force_remove_schedule(RuntimeOrigin::root(), other, 0));
+		assert_eq!(Erc::locked_balance(&other), 40);
+	});
+
+	// Genesis accepts schedules along with balances
+	let mut ext: sp_io::TestExternalities = pallet_erc20::GenesisConfig::<Test> {
+		balances: vec![(2, 1000)],
+		vesting: vec![(2, 1000, 0, 0, 100)],
+		..Default::default()
+	}
+	.build_storage()
+	.unwrap()
+	.into();
+
+	ext.execute_with(|| {
+		System::set_block_number(50);
+		assert_eq!(Erc::locked_balance(&2), 500);
+	});
+}
+
+#[test]
+#[should_panic(expected = "Genesis vesting schedules lock more than account holds")]
+fn genesis_vesting_over_balance_is_rejected() {
+	// Every schedule fits into the balance, but not both of them
+	let _ = pallet_erc20::GenesisConfig::<Test> {
+		balances: vec![(2, 1000)],
+		vesting: vec![(2, 600, 0, 0, 100), (2, 600, 0, 0, 100)],
+		..Default::default()
+	}
+	.build_storage();
+}
+
+#[test]
+fn permit_sets_allowance_with_owner_signature() {
+	use frame_support::{assert_noop, assert_ok, traits::ReservableCurrency};
+
+	ExtBuilder::default().build().execute_with(|| {
+		let (relayer, (owner, spender)) = (whitelisted_caller::<u64>(), (2, 3));
+		let payload = Erc::permit_payload(&owner, &spender, 300, 10);
+
+		assert_noop!(
+			Erc::permit(
+				RuntimeOrigin::signed(relayer),
+				owner,
+				spender,
+				300,
+				0,
+				TestSignature(owner, Erc::permit_payload(&owner, &spender, 300, 0))
+			),
+			crate::Error::<Test>::PermitExpired
+		);
+		assert_noop!(
+			Erc::permit(
+				RuntimeOrigin::signed(relayer),
+				owner,
+				spender,
+				300,
+				10,
+				TestSignature(spender, payload.clone())
+			),
+			crate::Error::<Test>::BadSignature
+		);
+		// Signature is bound to the instance it was made for
+		assert_noop!(
+			StableErc::permit(
+				RuntimeOrigin::signed(relayer),
+				owner,
+				spender,
+				300,
+				10,
+				TestSignature(owner, payload.clone())
+			),
+			pallet_erc20::Error::<Test, Instance1>::BadSignature
+		);
+
+		assert_ok!(Erc::permit(
+			RuntimeOrigin::signed(relayer),
+			owner,
+			spender,
+			300,
+			10,
+			TestSignature(owner, payload.clone())
+		));
+		System::assert_last_event(
+			crate::Event::Approval { from: owner, to: spender, value: 300 }.into(),
+		);
+		assert_eq!(Erc::allowance_of(owner, spender), 300);
+		assert_eq!(Erc::permit_nonce(owner), 1);
+		assert_eq!(Balances::reserved_balance(owner), ALLOWANCE_DEPOSIT);
+
+		// Nonce has changed, so the same signature can't be replayed
+		assert_noop!(
+			Erc::permit(
+				RuntimeOrigin::signed(relayer),
+				owner,
+				spender,
+				300,
+				10,
+				TestSignature(owner, payload)
+			),
+			crate::Error::<Test>::BadSignature
+		);
+	});
+}
+
+#[test]
+fn transfer_authorization_is_used_once_within_its_bounds() {
+	use frame_support::{assert_noop, assert_ok};
+
+	ExtBuilder::default().build().execute_with(|| {
+		let (relayer, (payer, merchant)) = (whitelisted_caller::<u64>(), (2, 3));
+		let (nonce, other_nonce) = ([1; 32], [2; 32]);
+
+		assert_ok!(Erc::transfer(RuntimeOrigin::signed(relayer), payer, 1000));
+
+		let authorize = |valid_after: u64, valid_before: u64, signer: u64, nonce: [u8; 32]| {
+			let payload = Erc::transfer_authorization_payload(
+				&payer,
+				&merchant,
+				300,
+				valid_after,
+				valid_before,
+				&nonce,
+			);
+
+			Erc::transfer_with_authorization(
+				RuntimeOrigin::signed(relayer),
+				payer,
+				merchant,
+				300,
+				valid_after,
+				valid_before,
+				nonce,
+				TestSignature(signer, payload),
+			)
+		};
+
+		// Current block is 1, it has to be strictly within bounds
+		assert_noop!(
+			authorize(1, 10, payer, nonce),
+			crate::Error::<Test>::AuthorizationNotYetValid
+		);
+		assert_noop!(authorize(0, 1, payer, nonce), crate::Error::<Test>::AuthorizationExpired);
+		assert_noop!(authorize(0, 10, merchant, nonce), crate::Error::<Test>::BadSignature);
+
+		assert_ok!(authorize(0, 10, payer, nonce));
+		System::assert_last_event(
+			crate::Event::AuthorizationUsed { authorizer: payer, nonce }.into(),
+		);
+		assert_eq!(Erc::balance_of(payer), 700);
+		assert_eq!(Erc::balance_of(merchant), 300);
+
+		assert_noop!(authorize(0, 10, payer, nonce), crate::Error::<Test>::AuthorizationUsed);
+
+		// Nonces are random, so any unused one works
+		assert_ok!(Erc::cancel_authorization(
+			RuntimeOrigin::signed(relayer),
+			payer,
+			other_nonce,
+			TestSignature(payer, Erc::cancel_authorization_payload(&payer, &other_nonce))
+		));
+		assert!(Erc::is_authorization_used(&payer, &other_nonce));
+		assert_noop!(
+			authorize(0, 10, payer, other_nonce),
+			crate::Error::<Test>::AuthorizationUsed
+		);
+
+		// Payer signed for exactly 300, dust left behind can't be swept along with it
+		assert_ok!(Erc::transfer(RuntimeOrigin::signed(payer), relayer, 395));
+		assert_noop!(authorize(0, 10, payer, [3; 32]), crate::Error::<Test>::WouldKillAccount);
+		assert_eq!(Erc::balance_of(payer), 305);
+	});
+}
+
+#[test]
+fn delegated_votes_follow_balances_and_keep_history() {
+	use frame_support::assert_ok;
+
+	ExtBuilder::default().build().execute_with(|| {
+		let (owner, (holder, delegate)) = (whitelisted_caller::<u64>(), (2, 3));
+
+		// Nothing counts as votes until it's delegated, even to the holder itself
+		assert_ok!(Erc::transfer(RuntimeOrigin::signed(owner), holder, 500));
+		assert_eq!(Erc::get_votes(holder), 0);
+
+		assert_ok!(Erc::delegate(RuntimeOrigin::signed(owner), delegate));
+		System::assert_has_event(
+			crate::Event::DelegateChanged { delegator: owner, from: None, to: delegate }.into(),
+		);
+		assert_ok!(Erc::delegate(RuntimeOrigin::signed(holder), holder));
+		assert_eq!(Erc::get_votes(delegate), 1500);
+		assert_eq!(Erc::get_votes(holder), 500);
+
+		System::set_block_number(2);
+		assert_ok!(Erc::transfer(RuntimeOrigin::signed(owner), holder, 300));
+		assert_eq!(Erc::get_votes(delegate), 1200);
+		assert_eq!(Erc::get_votes(holder), 800);
+
+		System::set_block_number(3);
+		assert_ok!(Erc::issue(RuntimeOrigin::root(), holder, 100));
+		assert_ok!(Erc::burn(RuntimeOrigin::signed(owner), 200));
+		assert_eq!(Erc::get_votes(holder), 900);
+		assert_eq!(Erc::get_votes(delegate), 1000);
+
+		System::set_block_number(4);
+		assert_ok!(Erc::delegate(RuntimeOrigin::signed(owner), holder));
+		assert_eq!(Erc::get_votes(delegate), 0);
+		assert_eq!(Erc::get_votes(holder), 1900);
+
+		// Only blocks that are already over can be looked up
+		assert_eq!(Erc::get_past_votes(&holder, 4), None);
+		assert_eq!(Erc::get_past_votes(&holder, 0), Some(0));
+		assert_eq!(Erc::get_past_votes(&holder, 1), Some(500));
+		assert_eq!(Erc::get_past_votes(&holder, 2), Some(800));
+		assert_eq!(Erc::get_past_votes(&holder, 3), Some(900));
+		assert_eq!(Erc::get_past_votes(&delegate, 1), Some(1500));
+		assert_eq!(Erc::get_past_votes(&delegate, 3), Some(1000));
+
+		System::set_block_number(5);
+		assert_eq!(Erc::get_past_votes(&delegate, 4), Some(0));
+		assert_eq!(Erc::get_past_votes(&holder, 4), Some(1900));
+
+		// Every block gets a single checkpoint holding votes from its end
+		assert_eq!(Erc::num_checkpoints(holder), 4);
+		assert_ok!(Erc::issue(RuntimeOrigin::root(), holder, 100));
+		assert_ok!(Erc::issue(RuntimeOrigin::root(), holder, 100));
+		assert_eq!(Erc::num_checkpoints(holder), 5);
+
+		System::set_block_number(6);
+		assert_eq!(Erc::get_past_votes(&holder, 5), Some(2100));
+		assert_eq!(Erc::get_past_votes(&holder, 3), Some(900));
+	});
+}
+
+#[test]
+fn burn_destroys_tokens_and_spends_allowance() {
+	use frame_support::{assert_noop, assert_ok};
+
+	ExtBuilder::default().build().execute_with(|| {
+		let (owner, spender) = (whitelisted_caller::<u64>(), 2);
+
+		assert_noop!(
+			Erc::burn(RuntimeOrigin::signed(spender), 100),
+			crate::Error::<Test>::NotEnoughFunds
+		);
+
+		assert_ok!(Erc::burn(RuntimeOrigin::signed(owner), 500));
+		System::assert_last_event(crate::Event::Burned { from: owner, value: 500 }.into());
+		assert_eq!(Erc::balance_of(owner), 1500);
+		assert_eq!(Erc::total_supply(), 1500);
+
+		assert_noop!(
+			Erc::burn_from(RuntimeOrigin::signed(spender), owner, 100),
+			crate::Error::<Test>::NoAllowance
+		);
+
+		assert_ok!(Erc::approve(RuntimeOrigin::signed(owner), spender, 300, None));
+		assert_noop!(
+			Erc::burn_from(RuntimeOrigin::signed(spender), owner, 301),
+			crate::Error::<Test>::NotEnoughAllowance
+		);
+
+		assert_ok!(Erc::burn_from(RuntimeOrigin::signed(spender), owner, 200));
+		System::assert_has_event(crate::Event::Burned { from: owner, value: 200 }.into());
+		assert_eq!(Erc::allowance_of(owner, spender), 100);
+		assert_eq!(Erc::balance_of(owner), 1300);
+		assert_eq!(Erc::balance_of(spender), 0);
+		assert_eq!(Erc::total_supply(), 1300);
+	});
+}
+
+#[test]
+fn supply_cap_limits_circulating_supply() {
+	use frame_support::{assert_noop, assert_ok};
+
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(Erc::total_supply(), 2000);
+		assert_eq!(Erc::supply_cap(), TOTAL_SUPPLY);
+
+		assert_noop!(
+			Erc::set_supply_cap(RuntimeOrigin::root(), 1999),
+			crate::Error::<Test>::CapBelowSupply
+		);
+		assert_ok!(Erc::set_supply_cap(RuntimeOrigin::root(), 2500));
+		System::assert_last_event(crate::Event::SupplyCapSet { cap: 2500 }.into());
+
+		assert_noop!(
+			Erc::issue(RuntimeOrigin::root(), 2, 501),
+			crate::Error::<Test>::NotEnoughSupply
+		);
+		assert_ok!(Erc::issue(RuntimeOrigin::root(), 2, 500));
+		assert_eq!(Erc::total_supply(), 2500);
+
+		// Burned tokens make room under the cap again
+		assert_ok!(Erc::burn(RuntimeOrigin::signed(2), 100));
+		assert_ok!(Erc::issue(RuntimeOrigin::root(), 3, 100));
+		assert_noop!(
+			Erc::issue(RuntimeOrigin::root(), 3, MIN_BALANCE),
+			crate::Error::<Test>::NotEnoughSupply
+		);
+	});
+}
+
+#[test]
+#[should_panic(expected = "Genesis balances exceed supply cap")]
+fn genesis_balances_over_supply_cap_are_rejected() {
+	let _ = pallet_erc20::GenesisConfig::<Test> {
+		balances: vec![(2, 600), (3, 400)],
+		supply_cap: Some(999),
+		..Default::default()
+	}
+	.build_storage();
+}
+
+#[test]
+fn migration_to_v2_counts_circulating_supply() {
+	use frame_support::{
+		assert_ok,
+		traits::{OnRuntimeUpgrade, StorageVersion},
+	};
+
+	ExtBuilder::default().build().execute_with(|| {
+		// Before V2 total supply was amount of tokens left to issue
+		StorageVersion::new(1).put::<Erc>();
+		pallet_erc20::TotalSupply::<Test>::put(TOTAL_SUPPLY - 2000);
+
+		pallet_erc20::migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Erc::total_supply(), 2000);
+		assert_eq!(StorageVersion::get::<Erc>(), 2);
+		assert_ok!(Erc::issue(RuntimeOrigin::root(), 2, 100));
+	});
+}
+
+#[test]
+fn metadata_is_validated_set_and_cleared() {
+	use frame_support::{assert_noop, assert_ok, BoundedVec};
+	use sp_runtime::DispatchError::BadOrigin;
+
+	ExtBuilder::default().build().execute_with(|| {
+		let set_metadata = |name: &[u8], symbol: &[u8], uri: Option<Vec<u8>>| {
+			Erc::set_metadata(
+				RuntimeOrigin::root(),
+				name.to_vec(),
+				symbol.to_vec(),
+				9,
+				uri,
+				Some("Ether with fewer decimals".as_bytes().to_vec()),
+			)
+		};
+
+		assert_noop!(set_metadata(&[0xff, 0xfe], b"ETH", None), crate::Error::<Test>::BadMetadata);
+		assert_noop!(
+			set_metadata(b"Ether", &[b'E'; MAX_SYMBOL_LENGTH as usize + 1], None),
+			crate::Error::<Test>::BadMetadata
+		);
+		assert_noop!(
+			set_metadata(b"Ether", b"ETH", Some(vec![b'a'; MAX_URI_LENGTH as usize + 1])),
+			crate::Error::<Test>::BadMetadata
+		);
+		assert_noop!(
+			Erc::set_metadata(RuntimeOrigin::signed(2), b"Ether".to_vec(), vec![], 9, None, None),
+			BadOrigin
+		);
+
+		assert_ok!(set_metadata(b"Ether", b"ETH", Some(b"https://example.com/eth".to_vec())));
+		System::assert_last_event(
+			crate::Event::MetadataSet {
+				name: BoundedVec::truncate_from(b"Ether".to_vec()),
+				symbol: BoundedVec::truncate_from(b"ETH".to_vec()),
+				decimals: 9,
+			}
+			.into(),
+		);
+		assert_eq!(Erc::name(), b"Ether".to_vec());
+		assert_eq!(Erc::decimals(), 9);
+		assert_eq!(
+			pallet_erc20::Uri::<Test>::get().map(BoundedVec::into_inner),
+			Some(b"https://example.com/eth".to_vec())
+		);
+
+		assert_ok!(Erc::clear_metadata(RuntimeOrigin::root()));
+		System::assert_last_event(crate::Event::MetadataCleared.into());
+		assert!(Erc::name().is_empty());
+		assert!(Erc::symbol().is_empty());
+		assert_eq!(Erc::decimals(), 0);
+		assert_eq!(pallet_erc20::Uri::<Test>::get(), None);
+		assert_eq!(pallet_erc20::Description::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn migration_to_v3_stores_decimals() {
+	use crate::migrations::v3::MigrateToV3;
+	use frame_support::traits::{ConstU8, OnRuntimeUpgrade, StorageVersion};
+
+	ExtBuilder::default().build().execute_with(|| {
+		// Before V3 decimals weren't stored at all
+		StorageVersion::new(2).put::<Erc>();
+		pallet_erc20::Decimals::<Test>::kill();
+
+		MigrateToV3::<Test, ConstU8<8>>::on_runtime_upgrade();
+
+		assert_eq!(Erc::decimals(), 8);
+		assert_eq!(StorageVersion::get::<Erc>(), 3);
+
+		// Decimals that are already stored are kept
+		StorageVersion::new(2).put::<StableErc>();
+
+		MigrateToV3::<Test, ConstU8<8>, Instance1>::on_runtime_upgrade();
+
+		assert_eq!(StableErc::decimals(), 6);
+		assert_eq!(StorageVersion::get::<StableErc>(), 3);
+	});
+}
+
+#[test]
+fn pause_and_freeze_stop_token_movements() {
+	use crate::Error;
+	use frame_support::{assert_noop, assert_ok};
+
+	ExtBuilder::default().build().execute_with(|| {
+		let (owner, (spender, other)) = (whitelisted_caller::<u64>(), (2, 3));
+
+		assert_ok!(Erc::approve(RuntimeOrigin::signed(owner), spender, 500, None));
+
+		assert_ok!(Erc::pause(RuntimeOrigin::root()));
+		System::assert_last_event(crate::Event::Paused.into());
+
+		assert_noop!(
+			Erc::transfer(RuntimeOrigin::signed(owner), other, 100),
+			Error::<Test>::Paused
+		);
+		assert_noop!(
+			Erc::transfer_from(RuntimeOrigin::signed(spender), owner, other, 100),
+			Error::<Test>::Paused
+		);
+		assert_noop!(
+			Erc::approve(RuntimeOrigin::signed(owner), other, 100, None),
+			Error::<Test>::Paused
+		);
+		assert_noop!(
+			Erc::increase_allowance(RuntimeOrigin::signed(owner), spender, 100),
+			Error::<Test>::Paused
+		);
+		assert_noop!(Erc::issue(RuntimeOrigin::root(), other, 100), Error::<Test>::Paused);
+		assert_noop!(Erc::burn(RuntimeOrigin::signed(owner), 100), Error::<Test>::Paused);
+
+		assert_ok!(Erc::unpause(RuntimeOrigin::root()));
+		System::assert_last_event(crate::Event::Unpaused.into());
+		assert_ok!(Erc::transfer(RuntimeOrigin::signed(owner), other, 100));
+
+		assert_ok!(Erc::freeze(RuntimeOrigin::root(), other));
+		System::assert_last_event(crate::Event::Frozen { who: other }.into());
+
+		// Frozen account can neither send nor receive tokens, whoever moves them
+		assert_noop!(
+			Erc::transfer(RuntimeOrigin::signed(other), owner, 50),
+			Error::<Test>::AccountFrozen
+		);
+		assert_noop!(
+			Erc::transfer(RuntimeOrigin::signed(owner), other, 50),
+			Error::<Test>::AccountFrozen
+		);
+		assert_noop!(
+			Erc::transfer_from(RuntimeOrigin::signed(spender), owner, other, 50),
+			Error::<Test>::AccountFrozen
+		);
+		assert_noop!(Erc::issue(RuntimeOrigin::root(), other, 50), Error::<Test>::AccountFrozen);
+		assert_noop!(Erc::burn(RuntimeOrigin::signed(other), 50), Error::<Test>::AccountFrozen);
+
+		// Other accounts aren't affected
+		assert_ok!(Erc::transfer_from(RuntimeOrigin::signed(spender), owner, spender, 50));
+
+		assert_ok!(Erc::thaw(RuntimeOrigin::root(), other));
+		System::assert_last_event(crate::Event::Thawed { who: other }.into());
+		assert_ok!(Erc::transfer(RuntimeOrigin::signed(other), owner, 50));
+		assert_eq!(Erc::balance_of(other), 50);
+	});
+}
+
+#[test]
+fn approve_sets_exact_allowance_that_transfer_from_spends() {
+	use crate::{Error, Event};
+	use frame_support::{assert_noop, assert_ok};
+
+	ExtBuilder::default().build().execute_with(|| {
+		let (owner, (spender, other)) = (whitelisted_caller::<u64>(), (2, 3));
+
+		// Approving again replaces allowance instead of adding to it
+		assert_ok!(Erc::approve(RuntimeOrigin::signed(owner), spender, 300, None));
+		assert_ok!(Erc::approve(RuntimeOrigin::signed(owner), spender, 200, None));
+		System::assert_last_event(Event::Approval { from: owner, to: spender, value: 200 }.into());
+		assert_eq!(Erc::allowance_of(owner, spender), 200);
+
+		assert_ok!(Erc::increase_allowance(RuntimeOrigin::signed(owner), spender, 100));
+		System::assert_last_event(Event::Approval { from: owner, to: spender, value: 300 }.into());
+		assert_noop!(
+			Erc::increase_allowance(RuntimeOrigin::signed(owner), spender, Balance::MAX),
+			Error::<Test>::AllowanceOverflow
+		);
+
+		assert_ok!(Erc::decrease_allowance(RuntimeOrigin::signed(owner), spender, 50));
+		System::assert_last_event(Event::Approval { from: owner, to: spender, value: 250 }.into());
+		assert_noop!(
+			Erc::decrease_allowance(RuntimeOrigin::signed(owner), spender, 251),
+			Error::<Test>::NotEnoughAllowance
+		);
+
+		// Remaining allowance is emitted after every spending, as ERC-20 does
+		assert_ok!(Erc::transfer_from(RuntimeOrigin::signed(spender), owner, other, 100));
+		System::assert_has_event(Event::Approval { from: owner, to: spender, value: 150 }.into());
+		assert_eq!(Erc::allowance_of(owner, spender), 150);
+		assert_eq!(Erc::balance_of(other), 100);
+		assert_noop!(
+			Erc::transfer_from(RuntimeOrigin::signed(spender), owner, other, 151),
+			Error::<Test>::NotEnoughAllowance
+		);
+
+		// Zero allowance revokes it
+		assert_ok!(Erc::approve(RuntimeOrigin::signed(owner), spender, 0, None));
+		System::assert_last_event(Event::Approval { from: owner, to: spender, value: 0 }.into());
+		assert!(!crate::Allowances::<Test>::contains_key(owner, spender));
+		assert_noop!(
+			Erc::transfer_from(RuntimeOrigin::signed(spender), owner, other, 10),
+			Error::<Test>::NoAllowance
+		);
+	});
+}
+
+#[test]
+fn spenders_are_charged_for_swept_dust() {
+	use crate::Error;
+	use frame_support::{assert_noop, assert_ok};
+
+	ExtBuilder::default().build().execute_with(|| {
+		let (owner, spender) = (2, 3);
+		assert_ok!(Erc::transfer(RuntimeOrigin::signed(whitelisted_caller()), owner, 15));
+
+		// Taking 10 leaves 5 behind, which is dust and has to go along with it
+		assert_ok!(Erc::approve(RuntimeOrigin::signed(owner), spender, 10, None));
+		assert_noop!(
+			Erc::transfer_from(RuntimeOrigin::signed(spender), owner, spender, 10),
+			Error::<Test>::NotEnoughAllowance
+		);
+		assert_noop!(
+			Erc::burn_from(RuntimeOrigin::signed(spender), owner, 10),
+			Error::<Test>::NotEnoughAllowance
+		);
+
+		assert_ok!(Erc::approve(RuntimeOrigin::signed(owner), spender, 15, None));
+		assert_ok!(Erc::transfer_from(RuntimeOrigin::signed(spender), owner, spender, 10));
+		assert_eq!(Erc::balance_of(owner), 0);
+		assert_eq!(Erc::balance_of(spender), 15);
+		assert_eq!(Erc::allowance_of(owner, spender), 0);
+
+		assert_ok!(Erc::transfer(RuntimeOrigin::signed(spender), owner, 15));
+		assert_ok!(Erc::approve(RuntimeOrigin::signed(owner), spender, 15, None));
+		assert_ok!(Erc::burn_from(RuntimeOrigin::signed(spender), owner, 10));
+		assert_eq!(Erc::balance_of(owner), 0);
+		assert_eq!(Erc::allowance_of(owner, spender), 0);
+		assert_eq!(Erc::total_supply(), 1985);
+	});
+}
+
 // TODO: Add tests that will be checking for possibly failing scenarios
 // #[test]
 // fn transfer_works_for_basic_case() {
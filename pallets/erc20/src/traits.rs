@@ -0,0 +1,82 @@
+//! Hooks that let other pallets observe and restrict movements of this pallet's token without
+//! polling its events. Both are implemented for tuples, so a runtime can plug several handlers in
+//!
+//! `BenchmarkHelper` is only needed to benchmark [`permit()`](crate::Pallet::permit())
+
+use sp_runtime::DispatchResult;
+#[cfg(feature = "runtime-benchmarks")]
+use sp_runtime::{traits::IdentifyAccount, AccountId32, MultiSignature, MultiSigner};
+
+/// Called after tokens are transferred, issued or burned. Handlers run inside the same dispatch, so
+/// they must be cheap or their weight must be accounted for elsewhere
+pub trait OnTransfer<AccountId, Balance> {
+	/// `value` tokens were moved from `from` to `to`
+	fn on_transfer(from: &AccountId, to: &AccountId, value: Balance);
+
+	/// `value` new tokens were issued to `to`
+	fn on_issue(to: &AccountId, value: Balance);
+
+	/// `value` tokens of `from` were destroyed
+	fn on_burn(from: &AccountId, value: Balance);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<AccountId, Balance: Copy> OnTransfer<AccountId, Balance> for Tuple {
+	fn on_transfer(from: &AccountId, to: &AccountId, value: Balance) {
+		for_tuples!( #( Tuple::on_transfer(from, to, value); )* );
+	}
+
+	fn on_issue(to: &AccountId, value: Balance) {
+		for_tuples!( #( Tuple::on_issue(to, value); )* );
+	}
+
+	fn on_burn(from: &AccountId, value: Balance) {
+		for_tuples!( #( Tuple::on_burn(from, value); )* );
+	}
+}
+
+/// Checked before every transfer. Returning an error rejects the transfer with that error
+pub trait BeforeTransfer<AccountId, Balance> {
+	fn before_transfer(from: &AccountId, to: &AccountId, value: Balance) -> DispatchResult;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<AccountId, Balance: Copy> BeforeTransfer<AccountId, Balance> for Tuple {
+	fn before_transfer(from: &AccountId, to: &AccountId, value: Balance) -> DispatchResult {
+		for_tuples!( #( Tuple::before_transfer(from, to, value)?; )* );
+
+		Ok(())
+	}
+}
+
+/// Produces signatures [`permit()`](crate::Pallet::permit()) benchmark submits, since pallet can't
+/// sign anything with an arbitrary [`Config::OffchainSignature`](crate::Config::OffchainSignature)
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId, Public, Signature> {
+	/// Generates a new key and returns it along with the account it controls
+	fn signer() -> (Public, AccountId);
+
+	/// Signs `message` with the key generated by [`signer()`](BenchmarkHelper::signer())
+	fn sign(signer: &Public, message: &[u8]) -> Signature;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<AccountId32, MultiSigner, MultiSignature> for () {
+	fn signer() -> (MultiSigner, AccountId32) {
+		let public = sp_io::crypto::sr25519_generate(0.into(), None);
+		let signer = MultiSigner::Sr25519(public);
+
+		(signer.clone(), signer.into_account())
+	}
+
+	fn sign(signer: &MultiSigner, message: &[u8]) -> MultiSignature {
+		let MultiSigner::Sr25519(public) = signer else {
+			panic!("Only sr25519 keys are generated")
+		};
+
+		let signature = sp_io::crypto::sr25519_sign(0.into(), public, message)
+			.expect("Key was generated by `signer()`");
+
+		MultiSignature::Sr25519(signature)
+	}
+}
@@ -0,0 +1,59 @@
+//! Vesting of this instance's token. Schedules are kept in [`Vesting`] and nothing is unlocked
+//! explicitly: [`Pallet::locked_balance()`] works out how much they still lock at the current block
+//! and neither [`Pallet::_transfer()`] nor [`Pallet::_burn()`] takes it
+
+use super::*;
+use frame_support::ensure;
+use sp_runtime::DispatchResult;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Amount of tokens of `who` that are still locked by its vesting schedules
+	pub fn locked_balance(who: &T::AccountId) -> T::Balance {
+		Self::locked_by(&<Vesting<T, I>>::get(who), frame_system::Pallet::<T>::block_number())
+	}
+
+	/// Adds `schedule` to `who`, removing its finished schedules first. Tokens aren't moved here,
+	/// caller must make sure `who` gets them
+	pub fn add_vesting_schedule(
+		who: &T::AccountId,
+		schedule: VestingScheduleOf<T, I>,
+	) -> DispatchResult {
+		ensure!(schedule.is_valid(), Error::<T, I>::InvalidVestingSchedule);
+
+		Self::prune_vesting_schedules(who);
+
+		<Vesting<T, I>>::try_append(who, schedule)
+			.map_err(|_| Error::<T, I>::TooManyVestingSchedules)?;
+
+		Self::deposit_event(Event::VestingScheduleAdded { who: who.clone(), schedule });
+
+		Ok(())
+	}
+
+	/// Removes schedules of `who` that don't lock anything anymore and returns how much the rest
+	/// of them lock
+	pub fn prune_vesting_schedules(who: &T::AccountId) -> T::Balance {
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut schedules = <Vesting<T, I>>::get(who);
+
+		schedules.retain(|schedule| !schedule.locked_at(now).is_zero());
+
+		if schedules.is_empty() {
+			<Vesting<T, I>>::remove(who);
+		} else {
+			<Vesting<T, I>>::insert(who, &schedules);
+		}
+
+		Self::locked_by(&schedules, now)
+	}
+
+	/// Amount `schedules` lock altogether at block `now`
+	fn locked_by(
+		schedules: &[VestingScheduleOf<T, I>],
+		now: frame_system::pallet_prelude::BlockNumberFor<T>,
+	) -> T::Balance {
+		schedules
+			.iter()
+			.fold(Zero::zero(), |locked, schedule| locked.saturating_add(schedule.locked_at(now)))
+	}
+}
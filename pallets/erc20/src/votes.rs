@@ -0,0 +1,106 @@
+//! Voting power of this instance's token. Accounts choose a delegate with
+//! [`delegate()`](Pallet::delegate()) and their balance counts as votes of that delegate from then
+//! on. Every change of those votes is also recorded in [`VoteCheckpoints`] under the block it
+//! happened in, so [`Pallet::get_past_votes()`] can tell what they were at any block before
+
+use super::*;
+use frame_system::pallet_prelude::BlockNumberFor;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Voting power `who` had at the end of `block`, `None` unless `block` is already over
+	pub fn get_past_votes(who: &T::AccountId, block: BlockNumberFor<T>) -> Option<T::Balance> {
+		if block >= frame_system::Pallet::<T>::block_number() {
+			return None
+		}
+
+		// Votes didn't change between the latest checkpoint up to `block` and `block` itself. If
+		// there's no such checkpoint, `who` had no votes yet
+		let count = partition_point(Self::num_checkpoints(who), |index| {
+			<VoteCheckpoints<T, I>>::get(who, index).map_or(false, |(at, _)| at <= block)
+		});
+		let recorded = count
+			.checked_sub(1)
+			.and_then(|latest| <VoteCheckpoints<T, I>>::get(who, latest))
+			.map(|(_, votes)| votes);
+
+		Some(recorded.unwrap_or_else(Zero::zero))
+	}
+
+	/// Makes `delegatee` the delegate of `who`, moving voting power of `who` from its previous one
+	pub fn _delegate(who: &T::AccountId, delegatee: T::AccountId) {
+		let previous =
+			<Delegates<T, I>>::mutate(who, |delegate| delegate.replace(delegatee.clone()));
+
+		if previous.as_ref() == Some(&delegatee) {
+			return
+		}
+
+		Self::deposit_event(Event::DelegateChanged {
+			delegator: who.clone(),
+			from: previous.clone(),
+			to: delegatee.clone(),
+		});
+
+		Self::move_votes(previous.as_ref(), Some(&delegatee), Self::balance_of(who));
+	}
+
+	/// Must be called before balance of `who` is changed to `value`, moves the difference into or
+	/// out of votes of its delegate
+	pub fn update_delegated_votes(who: &T::AccountId, value: T::Balance) {
+		let Some(delegate) = Self::delegates(who) else { return };
+		let balance = Self::balance_of(who);
+
+		if value > balance {
+			Self::move_votes(None, Some(&delegate), value.saturating_sub(balance));
+		} else {
+			Self::move_votes(Some(&delegate), None, balance.saturating_sub(value));
+		}
+	}
+
+	/// Takes `amount` of votes from `from` and gives them to `to`. `None` stands for tokens that
+	/// aren't delegated to anyone, or didn't exist before
+	fn move_votes(from: Option<&T::AccountId>, to: Option<&T::AccountId>, amount: T::Balance) {
+		if amount.is_zero() || from == to {
+			return
+		}
+
+		if let Some(from) = from {
+			Self::set_votes(from, Self::get_votes(from).saturating_sub(amount));
+		}
+
+		if let Some(to) = to {
+			Self::set_votes(to, Self::get_votes(to).saturating_add(amount));
+		}
+	}
+
+	/// Writes voting power of `delegate` along with its checkpoint for the current block. Later
+	/// changes within the same block overwrite that checkpoint
+	fn set_votes(delegate: &T::AccountId, votes: T::Balance) {
+		let previous = Self::get_votes(delegate);
+
+		if votes.is_zero() {
+			<Votes<T, I>>::remove(delegate);
+		} else {
+			<Votes<T, I>>::insert(delegate, votes);
+		}
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let count = Self::num_checkpoints(delegate);
+		let latest = count.checked_sub(1).filter(|&latest| {
+			<VoteCheckpoints<T, I>>::get(delegate, latest).map_or(false, |(at, _)| at == now)
+		});
+
+		if let Some(latest) = latest {
+			<VoteCheckpoints<T, I>>::insert(delegate, latest, (now, votes));
+		} else {
+			<VoteCheckpoints<T, I>>::insert(delegate, count, (now, votes));
+			<NumCheckpoints<T, I>>::insert(delegate, count.saturating_add(1));
+		}
+
+		Self::deposit_event(Event::DelegateVotesChanged {
+			delegate: delegate.clone(),
+			previous,
+			new: votes,
+		});
+	}
+}
@@ -8,7 +8,7 @@ use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
-	create_runtime_str, generic, impl_opaque_keys, traits::{BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify}, transaction_validity::{TransactionSource, TransactionValidity}, ApplyExtrinsicResult, MultiSignature
+	create_runtime_str, generic, impl_opaque_keys, traits::{AccountIdConversion, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify}, transaction_validity::{TransactionSource, TransactionValidity}, ApplyExtrinsicResult, MultiSignature
 };
 use sp_std::prelude::*;
 #[cfg(feature = "std")]
@@ -19,8 +19,8 @@ use frame_support::genesis_builder_helper::{build_config, create_default_config}
 pub use frame_support::{
 	construct_runtime, derive_impl, parameter_types,
 	traits::{
-		ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness,
-		StorageInfo,
+		ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, EitherOfDiverse, KeyOwnerProofSystem,
+		Randomness, StorageInfo,
 	},
 	weights::{
 		constants::{
@@ -28,7 +28,7 @@ pub use frame_support::{
 		},
 		IdentityFee, Weight,
 	},
-	StorageValue,
+	PalletId, StorageValue,
 };
 pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
@@ -39,8 +39,13 @@ use pallet_transaction_payment::{ConstFeeMultiplier, CurrencyAdapter, Multiplier
 pub use sp_runtime::BuildStorage;
 pub use sp_runtime::{Perbill, Permill};
 
+/// Runtime specific weights, one file per pallet instance
+pub mod weights;
+
 /// Import erc20 pallet
 pub use pallet_erc20;
+pub use pallet_erc20_tx_payment;
+pub use pallet_erc20_governance;
 
 // pub use pallet_template;
 
@@ -57,6 +62,9 @@ pub type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::Account
 /// Balance of an account.
 pub type Balance = u128;
 
+/// Balance of an account in ERC20 pallet.
+pub type ErcBalance = u32;
+
 /// Index of a transaction in the chain.
 pub type Nonce = u32;
 
@@ -99,10 +107,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -126,9 +134,25 @@ pub const DAYS: BlockNumber = HOURS * 24;
 // Constants for pallet-erc20
 pub const NAME: &'static str = "Bitcoin";
 pub const SYMBOL: &'static str = "BTC";
+pub const DECIMALS: u8 = 8;
+pub const STABLE_DECIMALS: u8 = 6;
 pub const TOTAL_SUPPLY: u32 = u32::MAX;
 pub const MAX_NAME_LENGTH: u8 = 50;
 pub const MAX_SYMBOL_LENGTH: u8 = 50;
+pub const MAX_URI_LENGTH: u32 = 128;
+pub const MAX_DESCRIPTION_LENGTH: u32 = 256;
+pub const MAX_BATCH_TRANSFERS: u32 = 128;
+pub const MAX_VESTING_SCHEDULES: u32 = 28;
+pub const MIN_VESTED_TRANSFER: ErcBalance = 100 * MIN_BALANCE;
+pub const MIN_BALANCE: ErcBalance = 10;
+pub const ALLOWANCE_DEPOSIT: Balance = 100 * EXISTENTIAL_DEPOSIT;
+pub const ASSET_DEPOSIT: Balance = 1_000 * EXISTENTIAL_DEPOSIT;
+
+// Constants for pallet-erc20-governance
+pub const VOTING_PERIOD: BlockNumber = 7 * DAYS;
+pub const PROPOSAL_DEPOSIT: ErcBalance = 50 * MIN_BALANCE;
+pub const MAX_CALL_LENGTH: u32 = 16 * 1024;
+pub const MAX_PROPOSALS: u32 = 32;
 
 /// The version information used to identify this runtime when compiled natively.
 #[cfg(feature = "std")]
@@ -153,8 +177,29 @@ parameter_types! {
 
 	pub const MaxNameLength: u8 = MAX_NAME_LENGTH;
 	pub const MaxSymbolLength: u8 = MAX_SYMBOL_LENGTH;
+	pub const MaxUriLength: u32 = MAX_URI_LENGTH;
+	pub const MaxDescriptionLength: u32 = MAX_DESCRIPTION_LENGTH;
+	pub const MaxBatchTransfers: u32 = MAX_BATCH_TRANSFERS;
+	pub const MaxVestingSchedules: u32 = MAX_VESTING_SCHEDULES;
+	pub const MinVestedTransfer: ErcBalance = MIN_VESTED_TRANSFER;
+	pub const MinBalance: ErcBalance = MIN_BALANCE;
+	pub const AllowanceDeposit: Balance = ALLOWANCE_DEPOSIT;
+	pub const AssetDeposit: Balance = ASSET_DEPOSIT;
+
+	pub const ErcGovernancePalletId: PalletId = PalletId(*b"erc/govn");
+	pub const VotingPeriod: BlockNumber = VOTING_PERIOD;
+	pub const ProposalDeposit: ErcBalance = PROPOSAL_DEPOSIT;
+	/// At least a tenth of all Erc tokens has to vote on proposal
+	pub const Quorum: Perbill = Perbill::from_percent(10);
+	pub const MaxCallLength: u32 = MAX_CALL_LENGTH;
+	pub const MaxProposals: u32 = MAX_PROPOSALS;
 }
 
+/// Origin of privileged calls: proposals approved by Erc holders, or Root. Sudo must stay until
+/// `pallet_erc20_governance` weights are replaced with benchmark results by `scripts/benchmark.sh`
+pub type ErcForceOrigin =
+	EitherOfDiverse<frame_system::EnsureRoot<AccountId>, pallet_erc20_governance::EnsureApproved>;
+
 /// The default types are being injected by [`derive_impl`](`frame_support::derive_impl`) from
 /// [`SoloChainDefaultConfig`](`struct@frame_system::config_preludes::SolochainDefaultConfig`),
 /// but overridden as needed.
@@ -243,16 +288,95 @@ impl pallet_erc20::Config for Runtime {
 
 	type MaxSymbolLength = MaxSymbolLength;
 
-	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxUriLength = MaxUriLength;
+
+	type MaxDescriptionLength = MaxDescriptionLength;
+
+	type MaxBatchTransfers = MaxBatchTransfers;
+
+	type MaxVestingSchedules = MaxVestingSchedules;
+
+	type MinVestedTransfer = MinVestedTransfer;
+
+	type OnTransfer = ();
+
+	type BeforeTransfer = ();
+
+	type OffchainSignature = Signature;
+
+	type OffchainPublic = <Signature as Verify>::Signer;
+
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+
+	type MinBalance = MinBalance;
+
+	type Currency = Balances;
+
+	type AllowanceDeposit = AllowanceDeposit;
+
+	type AssetDeposit = AssetDeposit;
+
+	type ForceOrigin = ErcForceOrigin;
 
 	type Supply = ConstU32<TOTAL_SUPPLY>;
-	type WeightInfo = pallet_erc20::weights::SubstrateWeight<Runtime>;
+	type WeightInfo = weights::pallet_erc20_erc::WeightInfo<Runtime>;
 	
-	type Balance = u32;
+	type Balance = ErcBalance;
+}
+
+/// Stable token, second instance of erc20 pallet with its own storage, events and weights
+pub type StableErcInstance = pallet_erc20::Instance1;
+
+impl pallet_erc20::Config<StableErcInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+
+	type MaxNameLength = MaxNameLength;
+
+	type MaxSymbolLength = MaxSymbolLength;
+
+	type MaxUriLength = MaxUriLength;
+
+	type MaxDescriptionLength = MaxDescriptionLength;
+
+	type MaxBatchTransfers = MaxBatchTransfers;
+
+	type MaxVestingSchedules = MaxVestingSchedules;
+
+	type MinVestedTransfer = MinVestedTransfer;
+
+	type OnTransfer = ();
+
+	type BeforeTransfer = ();
+
+	type OffchainSignature = Signature;
+
+	type OffchainPublic = <Signature as Verify>::Signer;
+
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+
+	type MinBalance = MinBalance;
+
+	type Currency = Balances;
+
+	type AllowanceDeposit = AllowanceDeposit;
+
+	type AssetDeposit = AssetDeposit;
+
+	type ForceOrigin = ErcForceOrigin;
+
+	type Supply = ConstU32<TOTAL_SUPPLY>;
+	type WeightInfo = weights::pallet_erc20_stable_erc::WeightInfo<Runtime>;
+
+	type Balance = ErcBalance;
 }
 
 parameter_types! {
 	pub FeeMultiplier: Multiplier = Multiplier::one();
+	pub const ErcTxPaymentPalletId: PalletId = PalletId(*b"erc/fees");
+	/// Keyless account that receives transaction fees paid in Erc
+	pub ErcFeeCollector: AccountId = ErcTxPaymentPalletId::get().into_account_truncating();
 }
 
 impl pallet_transaction_payment::Config for Runtime {
@@ -264,6 +388,42 @@ impl pallet_transaction_payment::Config for Runtime {
 	type FeeMultiplierUpdate = ConstFeeMultiplier<FeeMultiplier>;
 }
 
+impl pallet_erc20_tx_payment::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+
+	type Erc = Erc;
+
+	type FeeCollector = ErcFeeCollector;
+
+	type ForceOrigin = ErcForceOrigin;
+
+	type WeightInfo = pallet_erc20_tx_payment::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_erc20_governance::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+
+	type RuntimeOrigin = RuntimeOrigin;
+
+	type RuntimeCall = RuntimeCall;
+
+	type Erc = Erc;
+
+	type PalletId = ErcGovernancePalletId;
+
+	type VotingPeriod = VotingPeriod;
+
+	type ProposalDeposit = ProposalDeposit;
+
+	type Quorum = Quorum;
+
+	type MaxCallLength = MaxCallLength;
+
+	type MaxProposals = MaxProposals;
+
+	type WeightInfo = pallet_erc20_governance::weights::SubstrateWeight<Runtime>;
+}
+
 impl pallet_sudo::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -315,6 +475,15 @@ mod runtime {
 
 	#[runtime::pallet_index(8)]
 	pub type Erc = pallet_erc20;
+
+	#[runtime::pallet_index(9)]
+	pub type ErcTxPayment = pallet_erc20_tx_payment;
+
+	#[runtime::pallet_index(10)]
+	pub type StableErc = pallet_erc20<Instance1>;
+
+	#[runtime::pallet_index(11)]
+	pub type ErcGovernance = pallet_erc20_governance;
 }
 
 /// The address format for describing accounts.
@@ -332,14 +501,21 @@ pub type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
-	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_erc20_tx_payment::ChargeErcTransactionPayment<Runtime>,
 );
 
 /// All migrations of the runtime, aside from the ones declared in the pallets.
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	pallet_erc20::migrations::v1::MigrateToV1<Runtime>,
+	pallet_erc20::migrations::v1::MigrateToV1<Runtime, StableErcInstance>,
+	pallet_erc20::migrations::v2::MigrateToV2<Runtime>,
+	pallet_erc20::migrations::v2::MigrateToV2<Runtime, StableErcInstance>,
+	pallet_erc20::migrations::v3::MigrateToV3<Runtime, ConstU8<DECIMALS>>,
+	pallet_erc20::migrations::v3::MigrateToV3<Runtime, ConstU8<STABLE_DECIMALS>, StableErcInstance>,
+);
 
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
@@ -365,6 +541,9 @@ mod benches {
 		[pallet_timestamp, Timestamp]
 		[pallet_sudo, Sudo]
 		[pallet_erc20, Erc]
+		[pallet_erc20, StableErc]
+		[pallet_erc20_tx_payment, ErcTxPayment]
+		[pallet_erc20_governance, ErcGovernance]
 	);
 }
 
@@ -536,6 +715,53 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(4)]
+	impl pallet_erc20::runtime_api::Erc20Api<Block, AccountId, ErcBalance> for Runtime {
+		fn balance_of(who: AccountId) -> ErcBalance {
+			Erc::balance_of(who)
+		}
+
+		fn allowance(owner: AccountId, spender: AccountId) -> ErcBalance {
+			Erc::allowance_of(owner, spender)
+		}
+
+		fn total_supply() -> ErcBalance {
+			Erc::total_supply()
+		}
+
+		fn name() -> Vec<u8> {
+			Erc::name()
+		}
+
+		fn symbol() -> Vec<u8> {
+			Erc::symbol()
+		}
+
+		fn decimals() -> u8 {
+			Erc::decimals()
+		}
+
+		fn balance_of_at(who: AccountId, id: pallet_erc20::SnapshotId) -> Option<ErcBalance> {
+			Erc::balance_of_at(&who, id)
+		}
+
+		fn total_supply_at(id: pallet_erc20::SnapshotId) -> Option<ErcBalance> {
+			Erc::total_supply_at(id)
+		}
+
+		fn remaining_issuance() -> Option<ErcBalance> {
+			Erc::remaining_issuance()
+		}
+
+		fn get_votes(who: AccountId) -> ErcBalance {
+			Erc::get_votes(who)
+		}
+
+		fn get_past_votes(who: AccountId, block: BlockNumber) -> Option<ErcBalance> {
+			Erc::get_past_votes(&who, block)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -0,0 +1,39 @@
+#!/usr/bin/env bash
+# Regenerates every weights file of the workspace from its benchmarks. Run it from the root of
+# the repository on hardware the chain is meant to run on
+set -e
+
+NODE=./target/release/node-template
+PALLET_TEMPLATE=./pallets/frame-weight-template.hbs
+
+benchmark() {
+   $NODE benchmark pallet \
+      --chain=dev \
+      --extrinsic '*' \
+      --pallet "$1" \
+      --steps=50 \
+      --repeat=20 \
+      --wasm-execution=compiled \
+      "${@:2}"
+}
+
+echo "*** Building node with runtime benchmarks"
+cargo build --release --features runtime-benchmarks
+
+# Runtime benchmarks every `pallet_erc20` instance on its own and writes one file per instance,
+# named after it: `pallet_erc20_erc.rs` and `pallet_erc20_stable_erc.rs`
+echo "*** Benchmarking pallet_erc20 instances of the runtime"
+benchmark pallet_erc20 --output ./runtime/src/weights/
+
+# Pallet's own weights are taken from the `Erc` instance, rendered with the pallet template
+echo "*** Benchmarking pallets"
+PALLET_OUTPUT=$(mktemp -d)
+trap 'rm -rf "$PALLET_OUTPUT"' EXIT
+
+benchmark pallet_erc20 --template $PALLET_TEMPLATE --output "$PALLET_OUTPUT/"
+cp "$PALLET_OUTPUT/pallet_erc20_erc.rs" ./pallets/erc20/src/weights.rs
+
+benchmark pallet_erc20_tx_payment --template $PALLET_TEMPLATE \
+   --output ./pallets/erc20-tx-payment/src/weights.rs
+benchmark pallet_erc20_governance --template $PALLET_TEMPLATE \
+   --output ./pallets/erc20-governance/src/weights.rs